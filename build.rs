@@ -0,0 +1,15 @@
+use std::process::Command;
+
+/// Exposes the compiler version as `CODE_GEN_RUSTC_VERSION`. (for render cache keys)
+fn main() {
+    let rustc: String = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let version: String = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|version| version.trim().to_string())
+        .unwrap_or_default();
+    println!("cargo:rustc-env=CODE_GEN_RUSTC_VERSION={}", version);
+    println!("cargo:rerun-if-env-changed=RUSTC");
+}
@@ -0,0 +1,23 @@
+use std::any::Any;
+
+/// An element that can be converted to `Any` for downcasting.
+///
+/// This is implemented for every `'static` type. Note that `Box<dyn Statement>` is itself `Any`,
+/// so downcast boxed elements through the `dyn Statement` or `dyn Expression` helpers.
+pub trait AsAny: Any {
+    /// Converts the element to `Any`.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Converts the element to a mutable `Any`.
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: Any> AsAny for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
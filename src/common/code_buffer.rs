@@ -1,14 +1,43 @@
-use std::fmt::{Display, Formatter};
+use crate::{CLikeLanguage, CodeSink, Doc, FmtSink, IoSink, Language, Position, SourceMap};
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::sync::Arc;
 
 /// Responsible for buffering code.
-#[derive(Clone, Debug)]
-pub struct CodeBuffer {
+///
+/// # Streaming
+/// A code buffer with a sink flushes its code to the sink whenever the buffered code reaches the
+/// buffer capacity. The buffer only holds the code that has not yet been flushed and the first
+/// sink error is retained and returned by `flush`. The sink may borrow its writer for the lifetime
+/// `'a` of the buffer (ex: `&mut String`), the remaining code is flushed when the buffer is
+/// finished or dropped.
+///
+/// # Normalization
+/// A normalizing code buffer strips trailing whitespace, collapses runs of empty lines, drops
+/// empty lines after a block open or before a block close delimiter and ends the code with exactly
+/// one line-ending. Lines are normalized as they are ended, the final line-ending is written when
+/// the buffer is finished or converted into a string.
+///
+/// # Language
+/// The buffer carries the language of the written code. (C-like by default) Generic statements
+/// use the language for blocks, statement terminators, comments, strings and identifiers.
+pub struct CodeBuffer<'a> {
     indent: String,
     line_ending: String,
+    language: Arc<dyn Language>,
+    max_width: Option<usize>,
+    line_level: usize,
+    position: Position,
+    source_map: Option<SourceMap>,
+    normalizer: Option<Normalizer>,
+    capacity: usize,
+    sink: Option<Box<dyn CodeSink + 'a>>,
+    error: Option<io::Error>,
     code: String,
 }
 
-impl CodeBuffer {
+impl CodeBuffer<'_> {
     //! Constants
 
     /// The default indent. (4 spaces)
@@ -21,7 +50,7 @@ impl CodeBuffer {
     pub const DEFAULT_CAPACITY: usize = 4 * 1024;
 }
 
-impl CodeBuffer {
+impl CodeBuffer<'_> {
     //! Construction
 
     /// Creates a new code buffer.
@@ -33,12 +62,503 @@ impl CodeBuffer {
         Self {
             indent: indent.into(),
             line_ending: line_ending.into(),
+            language: Arc::new(CLikeLanguage::default()),
+            max_width: None,
+            line_level: 0,
+            position: Position::default(),
+            source_map: None,
+            normalizer: None,
+            capacity,
+            sink: None,
+            error: None,
             code: String::with_capacity(capacity),
         }
     }
 }
 
-impl Default for CodeBuffer {
+impl CodeBuffer<'_> {
+    //! Fork
+
+    /// Creates an empty in-memory buffer with the same configuration.
+    ///
+    /// The fork has the same indent, line-ending, language, capacity and max width, and normalizes
+    /// and records a source map if this buffer does.
+    pub fn fork(&self) -> CodeBuffer<'static> {
+        let mut fork: CodeBuffer = self.plain_fork();
+        if self.source_map.is_some() {
+            fork.enable_source_map();
+        }
+        if self.normalizer.is_some() {
+            fork.enable_normalization();
+        }
+        fork
+    }
+
+    /// Creates an empty in-memory buffer with the same indent, line-ending, language, capacity and
+    /// max width that neither normalizes nor records a source map.
+    pub(crate) fn plain_fork(&self) -> CodeBuffer<'static> {
+        let mut fork: CodeBuffer = CodeBuffer::new(
+            self.indent.as_str(),
+            self.line_ending.as_str(),
+            self.capacity,
+        );
+        fork.language = self.language.clone();
+        fork.max_width = self.max_width;
+        fork
+    }
+
+    /// Creates a function creating forks of this buffer. (the function can be shared by threads)
+    #[cfg(feature = "parallel")]
+    pub(crate) fn fork_fn(&self) -> impl Fn() -> CodeBuffer<'static> + Send + Sync {
+        let indent: String = self.indent.clone();
+        let line_ending: String = self.line_ending.clone();
+        let capacity: usize = self.capacity;
+        let language: Arc<dyn Language> = self.language.clone();
+        let max_width: Option<usize> = self.max_width;
+        let source_map: bool = self.source_map.is_some();
+        let normalization: bool = self.normalizer.is_some();
+        move || {
+            let mut fork: CodeBuffer =
+                CodeBuffer::new(indent.as_str(), line_ending.as_str(), capacity);
+            fork.language = language.clone();
+            fork.max_width = max_width;
+            if source_map {
+                fork.enable_source_map();
+            }
+            if normalization {
+                fork.enable_normalization();
+            }
+            fork
+        }
+    }
+
+    /// Hashes the configuration that affects the written code into the `state`.
+    pub(crate) fn hash_config<H: Hasher>(&self, state: &mut H) {
+        self.indent.hash(state);
+        self.line_ending.hash(state);
+        self.language.name().hash(state);
+        self.max_width.hash(state);
+    }
+
+    /// Appends the code and the recorded spans of the `fork`.
+    ///
+    /// Spans of the fork are nested within the innermost open span of this buffer. The empty lines
+    /// a normalizing fork drops at its start and holds pending at its end are normalized by this
+    /// buffer, so appending forks writes the same code as writing to this buffer directly.
+    pub fn append(&mut self, fork: CodeBuffer<'_>) {
+        self.append_parts(fork.into_parts());
+    }
+
+    /// Converts the fork into the parts appended by `append`.
+    pub(crate) fn into_parts(mut self) -> ForkParts {
+        let (leading_empty_line, trailing_empty_line): (bool, bool) = match self.normalizer {
+            Some(normalizer) => (normalizer.leading_empty_line, normalizer.pending_empty_line),
+            None => (false, false),
+        };
+        self.end_normalized_code();
+        ForkParts {
+            code: std::mem::take(&mut self.code),
+            source_map: self.source_map.take(),
+            leading_empty_line,
+            trailing_empty_line,
+        }
+    }
+
+    /// Appends the `parts` of a fork.
+    pub(crate) fn append_parts(&mut self, parts: ForkParts) {
+        let code: &str = parts.code.as_str();
+        if parts.leading_empty_line {
+            self.hold_empty_line();
+        }
+        let before: Position = self.position;
+        self.write(code);
+        if parts.trailing_empty_line {
+            self.hold_empty_line();
+        }
+        if let (Some(source_map), Some(fork_map)) = (&mut self.source_map, parts.source_map) {
+            let lines: usize = if self.line_ending.is_empty() {
+                0
+            } else {
+                code.matches(self.line_ending.as_str()).count()
+            };
+            let start: Position = Position {
+                byte: self.position.byte - code.len(),
+                line: self.position.line - lines,
+                column: if self.position.line - lines == before.line {
+                    before.column
+                } else {
+                    0
+                },
+            };
+            source_map.append(fork_map, start);
+        }
+    }
+}
+
+/// The code, source map and pending empty lines of a fork. (sent between threads)
+#[derive(Debug)]
+pub(crate) struct ForkParts {
+    code: String,
+    source_map: Option<SourceMap>,
+    leading_empty_line: bool,
+    trailing_empty_line: bool,
+}
+
+impl Clone for CodeBuffer<'_> {
+    /// Clones the buffer configuration and the buffered code. (the clone does not have a sink)
+    fn clone(&self) -> Self {
+        Self {
+            indent: self.indent.clone(),
+            line_ending: self.line_ending.clone(),
+            language: self.language.clone(),
+            max_width: self.max_width,
+            line_level: self.line_level,
+            position: self.position,
+            source_map: self.source_map.clone(),
+            normalizer: self.normalizer,
+            capacity: self.capacity,
+            sink: None,
+            error: None,
+            code: self.code.clone(),
+        }
+    }
+}
+
+impl Debug for CodeBuffer<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CodeBuffer")
+            .field("indent", &self.indent)
+            .field("line_ending", &self.line_ending)
+            .field("language", &self.language.name())
+            .field("max_width", &self.max_width)
+            .field("position", &self.position)
+            .field("source_map", &self.source_map)
+            .field("normalizing", &self.normalizer.is_some())
+            .field("capacity", &self.capacity)
+            .field("streaming", &self.sink.is_some())
+            .field("error", &self.error)
+            .field("code", &self.code)
+            .finish()
+    }
+}
+
+impl CodeBuffer<'_> {
+    //! Language
+
+    /// Gets the language.
+    pub fn language(&self) -> &Arc<dyn Language> {
+        &self.language
+    }
+
+    /// Sets the `language`.
+    pub fn set_language<L>(&mut self, language: L)
+    where
+        L: 'static + Language,
+    {
+        self.language = Arc::new(language);
+    }
+
+    /// Sets the `language`.
+    pub fn with_language<L>(mut self, language: L) -> Self
+    where
+        L: 'static + Language,
+    {
+        self.set_language(language);
+        self
+    }
+}
+
+impl<'a> CodeBuffer<'a> {
+    //! Streaming
+
+    /// Sets the `sink` that receives the flushed code.
+    ///
+    /// The code buffered while the buffer had no sink is written to the new sink with the next
+    /// flush. When a sink is replaced the buffered code is first flushed to the previous sink.
+    pub fn set_sink<S>(&mut self, sink: S)
+    where
+        S: 'a + CodeSink,
+    {
+        if self.sink.is_some() {
+            self.flush_code();
+        }
+        self.sink = Some(Box::new(sink));
+    }
+
+    /// Sets the `sink` that receives the flushed code.
+    pub fn with_sink<S>(mut self, sink: S) -> Self
+    where
+        S: 'a + CodeSink,
+    {
+        self.set_sink(sink);
+        self
+    }
+
+    /// Sets the io `writer` that receives the flushed code. (ex: `&mut File` or `StdoutLock`)
+    pub fn with_io_writer<W>(self, writer: W) -> Self
+    where
+        W: 'a + io::Write,
+    {
+        self.with_sink(IoSink::from(writer))
+    }
+
+    /// Sets the fmt `writer` that receives the flushed code. (ex: `&mut String` or `Formatter`)
+    pub fn with_fmt_writer<W>(self, writer: W) -> Self
+    where
+        W: 'a + std::fmt::Write,
+    {
+        self.with_sink(FmtSink::from(writer))
+    }
+
+    /// Checks if the buffer has a sink.
+    pub fn is_streaming(&self) -> bool {
+        self.sink.is_some()
+    }
+
+    /// Flushes the buffered code to the sink and flushes the sink.
+    ///
+    /// Returns the first error encountered by the sink. Does nothing without a sink.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.flush_code();
+        if let (Some(sink), None) = (&mut self.sink, &self.error) {
+            if let Err(error) = sink.flush_code() {
+                self.error = Some(error);
+            }
+        }
+        match self.error.take() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Ends the code, flushes the buffered code and consumes the buffer.
+    ///
+    /// Returns the first error encountered by the sink. (dropping a streaming buffer also flushes
+    /// the code but discards the errors)
+    pub fn finish(mut self) -> io::Result<()> {
+        self.end_normalized_code();
+        let result: io::Result<()> = self.flush();
+        self.sink = None;
+        result
+    }
+
+    /// Flushes the buffered code to the sink if the buffer is full.
+    fn flush_if_full(&mut self) {
+        if self.code.len() >= self.capacity {
+            self.flush_code();
+        }
+    }
+
+    /// Flushes the buffered code to the sink. (errors are retained)
+    ///
+    /// A normalizing buffer only flushes the ended lines.
+    fn flush_code(&mut self) {
+        if let Some(sink) = &mut self.sink {
+            let end: usize = match &mut self.normalizer {
+                Some(normalizer) => std::mem::take(&mut normalizer.line_start),
+                None => self.code.len(),
+            };
+            if self.error.is_none() {
+                if let Err(error) = sink.write_code(&self.code[..end]) {
+                    self.error = Some(error);
+                }
+            }
+            self.code.drain(..end);
+        }
+    }
+}
+
+impl CodeBuffer<'_> {
+    //! Max Width
+
+    /// Gets the optional max line width. (documents are written flat when `None`)
+    pub fn max_width(&self) -> Option<usize> {
+        self.max_width
+    }
+
+    /// Sets the `max_width` of lines.
+    pub fn set_max_width(&mut self, max_width: usize) {
+        self.max_width = Some(max_width);
+    }
+
+    /// Sets the `max_width` of lines.
+    pub fn with_max_width(mut self, max_width: usize) -> Self {
+        self.set_max_width(max_width);
+        self
+    }
+}
+
+impl CodeBuffer<'_> {
+    //! Source Map
+
+    /// Enables recording the source map of spans opened while writing.
+    pub fn enable_source_map(&mut self) {
+        if self.source_map.is_none() {
+            self.source_map = Some(SourceMap::default());
+        }
+    }
+
+    /// Enables recording the source map of spans opened while writing.
+    pub fn with_source_map(mut self) -> Self {
+        self.enable_source_map();
+        self
+    }
+
+    /// Gets the optional source map. (`None` if recording is not enabled)
+    pub fn source_map(&self) -> Option<&SourceMap> {
+        self.source_map.as_ref()
+    }
+
+    /// Takes the optional source map, leaving an empty source map if recording is enabled.
+    pub fn take_source_map(&mut self) -> Option<SourceMap> {
+        self.source_map.as_mut().map(std::mem::take)
+    }
+
+    /// Opens a span of the `kind` with the `name` at the current position.
+    ///
+    /// The name is only converted when recording is enabled.
+    pub fn open_span<S>(&mut self, kind: &'static str, name: S)
+    where
+        S: Into<String>,
+    {
+        if let Some(source_map) = &mut self.source_map {
+            source_map.open(kind, name.into(), self.position);
+        }
+    }
+
+    /// Closes the most recently opened span at the current position.
+    pub fn close_span(&mut self) {
+        if let Some(source_map) = &mut self.source_map {
+            source_map.close(self.position);
+        }
+    }
+}
+
+impl CodeBuffer<'_> {
+    //! Normalization
+
+    /// Enables the whitespace normalization of written lines.
+    pub fn enable_normalization(&mut self) {
+        if self.normalizer.is_none() {
+            self.normalizer = Some(Normalizer {
+                line_start: self.code.len(),
+                line_start_position: self.position,
+                pending_empty_line: false,
+                leading_empty_line: false,
+                after_open: true,
+            });
+        }
+    }
+
+    /// Enables the whitespace normalization of written lines.
+    pub fn with_normalization(mut self) -> Self {
+        self.enable_normalization();
+        self
+    }
+
+    /// Checks if the buffer normalizes written lines.
+    pub fn is_normalizing(&self) -> bool {
+        self.normalizer.is_some()
+    }
+
+    /// Writes the pending empty line before the first non-whitespace `code` of a line unless it
+    /// directly follows a block open delimiter or precedes a block close delimiter.
+    fn begin_normalized_content(&mut self, code: &str) {
+        let mut normalizer: Normalizer = match self.normalizer {
+            Some(normalizer) if normalizer.pending_empty_line => normalizer,
+            _ => return,
+        };
+        normalizer.pending_empty_line = false;
+        if normalizer.line_start_position == Position::default() {
+            normalizer.leading_empty_line = true;
+        }
+        let before_close: bool = match self.language.block_close() {
+            Some(close) => code.trim_start().starts_with(close),
+            None => false,
+        };
+        if !normalizer.after_open && !before_close {
+            let start: Position = normalizer.line_start_position;
+            self.code
+                .insert_str(normalizer.line_start, self.line_ending.as_str());
+            self.position.byte += self.line_ending.len();
+            self.position.line += 1;
+            if let Some(source_map) = &mut self.source_map {
+                source_map.shift(start, self.line_ending.len());
+            }
+            normalizer.line_start += self.line_ending.len();
+            normalizer.line_start_position = Position {
+                byte: start.byte + self.line_ending.len(),
+                line: start.line + 1,
+                column: 0,
+            };
+        }
+        self.normalizer = Some(normalizer);
+    }
+
+    /// Ends the current line with trailing whitespace stripped. Empty lines are held pending.
+    fn end_normalized_line(&mut self) {
+        let mut normalizer: Normalizer = match self.normalizer {
+            Some(normalizer) => normalizer,
+            None => return,
+        };
+        self.trim_normalized_line(&normalizer);
+        if self.code.len() == normalizer.line_start {
+            normalizer.pending_empty_line = true;
+        } else {
+            normalizer.after_open = self.code.ends_with(self.language.block_open());
+            self.code.push_str(self.line_ending.as_str());
+            self.position.byte += self.line_ending.len();
+            self.position.line += 1;
+            self.position.column = 0;
+            normalizer.line_start = self.code.len();
+            normalizer.line_start_position = self.position;
+        }
+        self.normalizer = Some(normalizer);
+        self.flush_if_full();
+    }
+
+    /// Strips the trailing whitespace of the current line.
+    fn trim_normalized_line(&mut self, normalizer: &Normalizer) {
+        let line: &str = &self.code[normalizer.line_start..];
+        let trimmed: &str = line.trim_end();
+        if trimmed.len() != line.len() {
+            let removed: usize = line.len() - trimmed.len();
+            if trimmed.is_empty() {
+                self.position = normalizer.line_start_position;
+            } else {
+                self.position.byte -= removed;
+                self.position.column -= line[trimmed.len()..].chars().count();
+            }
+            self.code.truncate(self.code.len() - removed);
+            if let Some(source_map) = &mut self.source_map {
+                source_map.clamp(self.position);
+            }
+        }
+    }
+
+    /// Holds an empty line pending if the current line of a normalizing buffer is empty.
+    fn hold_empty_line(&mut self) {
+        if let Some(normalizer) = &mut self.normalizer {
+            if self.code.len() == normalizer.line_start {
+                normalizer.pending_empty_line = true;
+            }
+        }
+    }
+
+    /// Ends the final line of a normalizing buffer and drops any pending empty lines.
+    fn end_normalized_code(&mut self) {
+        if let Some(normalizer) = self.normalizer {
+            if self.code.len() != normalizer.line_start {
+                self.end_normalized_line();
+            }
+            if let Some(normalizer) = &mut self.normalizer {
+                normalizer.pending_empty_line = false;
+            }
+        }
+    }
+}
+
+impl Default for CodeBuffer<'_> {
     fn default() -> Self {
         Self::new(
             Self::DEFAULT_INDENT,
@@ -48,44 +568,127 @@ impl Default for CodeBuffer {
     }
 }
 
-impl From<CodeBuffer> for String {
-    fn from(buffer: CodeBuffer) -> Self {
-        buffer.code
+impl Drop for CodeBuffer<'_> {
+    /// Ends the code and flushes the buffered code to the sink. (errors are discarded, use
+    /// `finish` to handle them)
+    fn drop(&mut self) {
+        if self.sink.is_some() {
+            self.end_normalized_code();
+            let _ = self.flush();
+        }
+    }
+}
+
+impl From<CodeBuffer<'_>> for String {
+    /// Converts the buffer into the buffered code.
+    ///
+    /// A streaming buffer only holds the code not yet flushed to its sink, so the converted code
+    /// is the unflushed tail of the code. (use `finish` to end streamed code)
+    fn from(mut buffer: CodeBuffer) -> Self {
+        buffer.end_normalized_code();
+        std::mem::take(&mut buffer.code)
     }
 }
 
-impl CodeBuffer {
+impl CodeBuffer<'_> {
     //! Access
 
-    /// Peeks at the buffered code.
+    /// Peeks at the buffered code. (excludes code flushed to the sink)
     pub fn peek(&self) -> &str {
         self.code.as_str()
     }
 
-    /// Clears the buffered code.
+    /// Gets the current position.
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Gets the current line index. (zero-based)
+    pub fn line_index(&self) -> usize {
+        self.position.line
+    }
+
+    /// Gets the current column index. (zero-based, in characters)
+    pub fn column(&self) -> usize {
+        self.position.column
+    }
+
+    /// Clears the buffered code, the position and the recorded source map.
     pub fn clear(&mut self) {
         self.code.clear();
+        self.line_level = 0;
+        self.position = Position::default();
+        if let Some(source_map) = &mut self.source_map {
+            source_map.clear();
+        }
+        if self.normalizer.take().is_some() {
+            self.enable_normalization();
+        }
     }
 }
 
-impl CodeBuffer {
+impl CodeBuffer<'_> {
     //! Writing
 
     /// Writes the `code`.
     pub fn write(&mut self, code: &str) {
+        if self.normalizer.is_some() && !self.line_ending.is_empty() {
+            if code.contains(self.line_ending.as_str()) {
+                let line_ending: String = self.line_ending.clone();
+                for (i, line) in code.split(line_ending.as_str()).enumerate() {
+                    if i != 0 {
+                        self.end_line();
+                    }
+                    self.write(line);
+                }
+                return;
+            }
+            if !code.trim().is_empty() {
+                self.begin_normalized_content(code);
+            }
+        }
         self.code.push_str(code);
+        self.position.byte += code.len();
+        let line_ending: &str = self.line_ending.as_str();
+        match code.rfind(line_ending).filter(|_| !line_ending.is_empty()) {
+            Some(index) => {
+                self.position.line += code.matches(line_ending).count();
+                self.position.column = code[index + line_ending.len()..].chars().count();
+            }
+            None => self.position.column += code.chars().count(),
+        }
+        self.flush_if_full();
+    }
+
+    /// Gets the width of the indent `level`. (in characters)
+    pub fn indent_width(&self, level: usize) -> usize {
+        self.indent.chars().count() * level
     }
 
     /// Writes the indent `level`.
     pub fn indent(&mut self, level: usize) {
+        self.line_level = level;
         for _ in 0..level {
-            self.code.push_str(self.indent.as_mut_str());
+            self.code.push_str(self.indent.as_str());
         }
+        self.position.byte += self.indent.len() * level;
+        self.position.column += self.indent.chars().count() * level;
+        self.flush_if_full();
     }
 
     /// Writes a line-ending.
     pub fn end_line(&mut self) {
+        if self.normalizer.is_some() && !self.line_ending.is_empty() {
+            self.end_normalized_line();
+            return;
+        }
         self.code.push_str(self.line_ending.as_str());
+        self.position.byte += self.line_ending.len();
+        if !self.line_ending.is_empty() {
+            self.position.line += 1;
+            self.position.column = 0;
+        }
+        self.flush_if_full();
     }
 
     /// Writes a line of `code` at the indent `level` with a line-ending.
@@ -98,11 +701,96 @@ impl CodeBuffer {
     /// Writes a single space.
     pub fn space(&mut self) {
         self.code.push(' ');
+        self.position.byte += 1;
+        self.position.column += 1;
+        self.flush_if_full();
     }
 }
 
-impl Display for CodeBuffer {
+impl CodeBuffer<'_> {
+    //! Documents
+
+    /// Writes the `doc`. (broken lines are indented relative to the level of the current line)
+    pub fn write_doc(&mut self, doc: &Doc) {
+        let max_width: usize = match self.max_width {
+            Some(max_width) => max_width,
+            None => return doc.write_flat(self),
+        };
+        let broken: bool = !self.fits(max_width, doc, &[]);
+        let mut commands: Vec<(usize, bool, &Doc)> = vec![(self.line_level, broken, doc)];
+        while let Some((level, broken, doc)) = commands.pop() {
+            match doc {
+                Doc::Text(text) => self.write(text.as_str()),
+                Doc::Line | Doc::SoftLine if broken => {
+                    self.end_line();
+                    self.indent(level);
+                }
+                Doc::Line => self.space(),
+                Doc::SoftLine => {}
+                Doc::IfBroken(text) => {
+                    if broken {
+                        self.write(text.as_str());
+                    }
+                }
+                Doc::Concat(docs) => {
+                    commands.extend(docs.iter().rev().map(|doc| (level, broken, doc)));
+                }
+                Doc::Nest(doc) => commands.push((level + 1, broken, doc)),
+                Doc::Group(doc) => {
+                    let broken: bool = broken && !self.fits(max_width, doc, &commands);
+                    commands.push((level, broken, doc));
+                }
+            }
+        }
+    }
+
+    /// Checks if the `doc` fits flat on the current line followed by the `rest` of the commands
+    /// up to their next line break.
+    fn fits(&self, max_width: usize, doc: &Doc, rest: &[(usize, bool, &Doc)]) -> bool {
+        let mut width: usize = self.position.column;
+        let mut rest: std::slice::Iter<(usize, bool, &Doc)> = rest.iter();
+        let mut stack: Vec<(bool, &Doc)> = vec![(false, doc)];
+        loop {
+            let (broken, doc) = match stack.pop() {
+                Some(command) => command,
+                None => match rest.next_back() {
+                    Some((_, broken, doc)) => (*broken, *doc),
+                    None => return true,
+                },
+            };
+            match doc {
+                Doc::Text(text) => width += text.chars().count(),
+                Doc::Line | Doc::SoftLine if broken => return true,
+                Doc::Line => width += 1,
+                Doc::SoftLine => {}
+                Doc::IfBroken(text) => {
+                    if broken {
+                        width += text.chars().count();
+                    }
+                }
+                Doc::Concat(docs) => stack.extend(docs.iter().rev().map(|doc| (broken, doc))),
+                Doc::Nest(doc) => stack.push((broken, doc)),
+                Doc::Group(doc) => stack.push((false, doc)),
+            }
+            if width > max_width {
+                return false;
+            }
+        }
+    }
+}
+
+impl Display for CodeBuffer<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.peek())
     }
 }
+
+/// The line state of a normalizing code buffer.
+#[derive(Copy, Clone, Debug)]
+struct Normalizer {
+    line_start: usize,
+    line_start_position: Position,
+    pending_empty_line: bool,
+    leading_empty_line: bool,
+    after_open: bool,
+}
@@ -0,0 +1,75 @@
+use std::fmt;
+use std::io;
+
+/// A sink that receives code flushed from a code buffer.
+pub trait CodeSink {
+    /// Writes the `code` to the sink.
+    fn write_code(&mut self, code: &str) -> io::Result<()>;
+
+    /// Flushes the sink.
+    fn flush_code(&mut self) -> io::Result<()>;
+}
+
+/// A code sink backed by an `io::Write`.
+#[derive(Debug)]
+pub struct IoSink<W: io::Write> {
+    writer: W,
+}
+
+impl<W: io::Write> From<W> for IoSink<W> {
+    fn from(writer: W) -> Self {
+        Self { writer }
+    }
+}
+
+impl<W: io::Write> IoSink<W> {
+    //! Access
+
+    /// Converts the sink into the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: io::Write> CodeSink for IoSink<W> {
+    fn write_code(&mut self, code: &str) -> io::Result<()> {
+        self.writer.write_all(code.as_bytes())
+    }
+
+    fn flush_code(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// A code sink backed by a `fmt::Write`.
+#[derive(Debug)]
+pub struct FmtSink<W: fmt::Write> {
+    writer: W,
+}
+
+impl<W: fmt::Write> From<W> for FmtSink<W> {
+    fn from(writer: W) -> Self {
+        Self { writer }
+    }
+}
+
+impl<W: fmt::Write> FmtSink<W> {
+    //! Access
+
+    /// Converts the sink into the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: fmt::Write> CodeSink for FmtSink<W> {
+    fn write_code(&mut self, code: &str) -> io::Result<()> {
+        self.writer
+            .write_str(code)
+            .map_err(|_| io::Error::other("formatter error"))
+    }
+
+    fn flush_code(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
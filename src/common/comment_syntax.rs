@@ -0,0 +1,114 @@
+use std::borrow::Cow;
+
+/// The comment delimiters of a language.
+///
+/// A syntax supports line comments, block comments or both.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct CommentSyntax {
+    line_prefix: Option<&'static str>,
+    block: Option<(&'static str, &'static str, &'static str)>,
+}
+
+impl CommentSyntax {
+    //! Presets
+
+    /// The C-like syntax. (`//` and `/* */`)
+    pub const C_LIKE: Self = Self::line("//").with_block("/*", " *", " */");
+
+    /// The hash syntax. (`#`)
+    pub const HASH: Self = Self::line("#");
+
+    /// The double-dash syntax. (`--` and `/* */`)
+    pub const DOUBLE_DASH: Self = Self::line("--").with_block("/*", " *", " */");
+
+    /// The XML syntax. (`<!-- -->`)
+    pub const XML: Self = Self::block("<!--", "", "-->");
+
+    /// The Rust outer doc syntax. (`///` and `/** */`)
+    pub const RUST_OUTER_DOC: Self = Self::line("///").with_block("/**", " *", " */");
+
+    /// The Rust inner doc syntax. (`//!` and `/*! */`)
+    pub const RUST_INNER_DOC: Self = Self::line("//!").with_block("/*!", " *", " */");
+}
+
+impl CommentSyntax {
+    //! Construction
+
+    /// Creates a syntax with the line comment `prefix`.
+    pub const fn line(prefix: &'static str) -> Self {
+        Self {
+            line_prefix: Some(prefix),
+            block: None,
+        }
+    }
+
+    /// Creates a syntax with the block comment `open`, line `prefix` and `close` delimiters.
+    pub const fn block(open: &'static str, prefix: &'static str, close: &'static str) -> Self {
+        Self {
+            line_prefix: None,
+            block: Some((open, prefix, close)),
+        }
+    }
+
+    /// Sets the block comment `open`, line `prefix` and `close` delimiters.
+    pub const fn with_block(
+        mut self,
+        open: &'static str,
+        prefix: &'static str,
+        close: &'static str,
+    ) -> Self {
+        self.block = Some((open, prefix, close));
+        self
+    }
+}
+
+impl Default for CommentSyntax {
+    fn default() -> Self {
+        Self::C_LIKE
+    }
+}
+
+impl CommentSyntax {
+    //! Properties
+
+    /// Gets the optional line comment prefix.
+    pub fn line_prefix(&self) -> Option<&'static str> {
+        self.line_prefix
+    }
+
+    /// Gets the optional block comment `(open, prefix, close)` delimiters.
+    pub fn block_delimiters(&self) -> Option<(&'static str, &'static str, &'static str)> {
+        self.block
+    }
+}
+
+impl CommentSyntax {
+    //! Escaping
+
+    /// Escapes the block comment delimiters in the text `line`. (ex: `*/` becomes `* /`)
+    ///
+    /// The close delimiter would end the comment early. The `/*` open delimiter is escaped as
+    /// well for C-like block comments since Rust block comments nest.
+    pub fn escape_block<'a>(&self, line: &'a str) -> Cow<'a, str> {
+        let mut line: Cow<str> = Cow::Borrowed(line);
+        if let Some((open, _, close)) = self.block {
+            let close: &str = close.trim();
+            if open.starts_with("/*") {
+                line = Self::escape(line, "/*");
+            }
+            line = Self::escape(line, close);
+        }
+        line
+    }
+
+    /// Escapes the `delimiter` in the `line` with a space before its last character.
+    fn escape<'a>(line: Cow<'a, str>, delimiter: &str) -> Cow<'a, str> {
+        match delimiter.char_indices().last() {
+            Some((last, _)) if last > 0 && line.contains(delimiter) => {
+                let escaped: String = format!("{} {}", &delimiter[..last], &delimiter[last..]);
+                Cow::Owned(line.replace(delimiter, escaped.as_str()))
+            }
+            _ => line,
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{CodeBuffer, Expression};
+
+/// A document for width-aware code layout.
+///
+/// Groups are written flat when they fit within the max width of the code buffer and are broken
+/// into multiple lines otherwise. Code buffers without a max width always write documents flat.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum Doc {
+    /// Text without line-endings.
+    Text(String),
+
+    /// A space, or a line-ending when the enclosing group is broken.
+    Line,
+
+    /// Nothing, or a line-ending when the enclosing group is broken.
+    SoftLine,
+
+    /// Text that is only written when the enclosing group is broken. (ex: trailing commas)
+    IfBroken(String),
+
+    /// A sequence of documents.
+    Concat(Vec<Doc>),
+
+    /// A document that is indented one level deeper when broken.
+    Nest(Box<Doc>),
+
+    /// A document that is written flat if it fits and broken otherwise.
+    Group(Box<Doc>),
+}
+
+impl From<&str> for Doc {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<String> for Doc {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl Doc {
+    //! Construction
+
+    /// Creates a sequence of documents.
+    pub fn concat<I>(docs: I) -> Self
+    where
+        I: IntoIterator<Item = Doc>,
+    {
+        Self::Concat(docs.into_iter().collect())
+    }
+
+    /// Creates a nested document.
+    pub fn nest<D>(doc: D) -> Self
+    where
+        D: Into<Doc>,
+    {
+        Self::Nest(Box::new(doc.into()))
+    }
+
+    /// Creates a group document.
+    pub fn group<D>(doc: D) -> Self
+    where
+        D: Into<Doc>,
+    {
+        Self::Group(Box::new(doc.into()))
+    }
+
+    /// Creates the comma separated `items` without a group. (ex: `a, b` or `\n    a,\n    b,\n`)
+    pub fn comma_separated(items: Vec<Doc>) -> Self {
+        if items.is_empty() {
+            Self::Concat(Vec::default())
+        } else {
+            Self::concat([Self::nested_items(Self::SoftLine, items), Self::SoftLine])
+        }
+    }
+
+    /// Creates a grouped comma list of the `items` in the `open` and `close` brackets.
+    /// (ex: `(a, b)`)
+    pub fn comma_list(open: &str, items: Vec<Doc>, close: &str) -> Self {
+        Self::group(Self::concat([
+            Self::from(open),
+            Self::comma_separated(items),
+            Self::from(close),
+        ]))
+    }
+
+    /// Creates a grouped comma list of the `items` in the `open` and `close` brackets with the
+    /// items padded by spaces when flat. (ex: `{ a, b }`)
+    pub fn spaced_comma_list(open: &str, items: Vec<Doc>, close: &str) -> Self {
+        if items.is_empty() {
+            Self::concat([Self::from(open), Self::from(close)])
+        } else {
+            Self::group(Self::concat([
+                Self::from(open),
+                Self::nested_items(Self::Line, items),
+                Self::Line,
+                Self::from(close),
+            ]))
+        }
+    }
+
+    /// Creates the nested comma separated `items` with a trailing comma when broken.
+    fn nested_items(first_break: Doc, items: Vec<Doc>) -> Self {
+        let mut inner: Vec<Doc> = Vec::with_capacity(items.len() * 3 + 1);
+        inner.push(first_break);
+        for (i, item) in items.into_iter().enumerate() {
+            if i != 0 {
+                inner.push(Self::from(","));
+                inner.push(Self::Line);
+            }
+            inner.push(item);
+        }
+        inner.push(Self::IfBroken(",".to_string()));
+        Self::nest(Self::Concat(inner))
+    }
+}
+
+impl Doc {
+    //! Flat
+
+    /// Writes the document flat to the buffer `b`, ignoring all line breaks.
+    pub(crate) fn write_flat(&self, b: &mut CodeBuffer) {
+        match self {
+            Self::Text(text) => b.write(text.as_str()),
+            Self::Line => b.space(),
+            Self::SoftLine | Self::IfBroken(_) => {}
+            Self::Concat(docs) => docs.iter().for_each(|doc| doc.write_flat(b)),
+            Self::Nest(doc) | Self::Group(doc) => doc.write_flat(b),
+        }
+    }
+}
+
+impl Expression for Doc {
+    fn write(&self, b: &mut CodeBuffer) {
+        b.write_doc(self);
+    }
+
+    fn to_doc(&self) -> Doc {
+        self.clone()
+    }
+}
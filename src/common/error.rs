@@ -0,0 +1,95 @@
+use crate::{ProtectedRegionError, TemplateError, ValidationError};
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+/// An error of any operation of the crate.
+#[derive(Debug)]
+pub enum Error {
+    /// An I/O error reading or writing files.
+    Io(std::io::Error),
+
+    /// An error parsing or binding a template.
+    Template(TemplateError),
+
+    /// An error extracting protected regions.
+    ProtectedRegion(ProtectedRegionError),
+
+    /// The protected regions of an existing file that would be orphaned by the rendered file.
+    OrphanedRegions { path: PathBuf, ids: Vec<String> },
+
+    /// An output path that is not a relative path within the root directory.
+    InvalidPath(PathBuf),
+
+    /// A generated file that was edited since it was generated.
+    HandEdited(PathBuf),
+
+    /// The errors validating a code model. (never empty)
+    Validation(Vec<ValidationError>),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<TemplateError> for Error {
+    fn from(error: TemplateError) -> Self {
+        Self::Template(error)
+    }
+}
+
+impl From<ProtectedRegionError> for Error {
+    fn from(error: ProtectedRegionError) -> Self {
+        Self::ProtectedRegion(error)
+    }
+}
+
+impl From<Vec<ValidationError>> for Error {
+    fn from(errors: Vec<ValidationError>) -> Self {
+        Self::Validation(errors)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "{}", error),
+            Self::Template(error) => write!(f, "{}", error),
+            Self::ProtectedRegion(error) => write!(f, "{}", error),
+            Self::OrphanedRegions { path, ids } => write!(
+                f,
+                "orphaned protected regions in {}: {}",
+                path.display(),
+                ids.join(", ")
+            ),
+            Self::InvalidPath(path) => write!(f, "invalid output path: {}", path.display()),
+            Self::HandEdited(path) => {
+                write!(
+                    f,
+                    "refusing to overwrite hand-edited file: {}",
+                    path.display()
+                )
+            }
+            Self::Validation(errors) => {
+                write!(f, "{} validation error(s)", errors.len())?;
+                for error in errors {
+                    write!(f, "\n  {}", error)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Template(error) => Some(error),
+            Self::ProtectedRegion(error) => Some(error),
+            Self::OrphanedRegions { .. } | Self::InvalidPath(_) | Self::HandEdited(_) => None,
+            Self::Validation(_) => None,
+        }
+    }
+}
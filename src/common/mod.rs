@@ -1,9 +1,33 @@
+pub use as_any::*;
 pub use code_buffer::*;
+pub use code_sink::*;
+pub use comment_syntax::*;
+pub use doc::*;
+pub use error::*;
 pub use is_empty::*;
+pub use node::*;
+pub use node_mut::*;
 pub use source::*;
+pub use source_map::*;
+pub use stable_hasher::*;
+pub use stable_hasher_128::*;
+pub use validation_error::*;
+pub use validation_error_kind::*;
 pub use with_name::*;
 
+mod as_any;
 mod code_buffer;
+mod code_sink;
+mod comment_syntax;
+mod doc;
+mod error;
 mod is_empty;
+mod node;
+mod node_mut;
 mod source;
+mod source_map;
+mod stable_hasher;
+mod stable_hasher_128;
+mod validation_error;
+mod validation_error_kind;
 mod with_name;
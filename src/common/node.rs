@@ -0,0 +1,61 @@
+use crate::{Expression, Statement};
+use std::any::Any;
+
+/// A node of a statement tree.
+#[derive(Copy, Clone)]
+pub enum Node<'a> {
+    /// A statement.
+    Statement(&'a dyn Statement),
+
+    /// A list of statements.
+    Statements(&'a [Box<dyn Statement>]),
+
+    /// An expression.
+    Expression(&'a dyn Expression),
+
+    /// The comment lines of an element.
+    Comments(&'a [String]),
+}
+
+impl<'a> Node<'a> {
+    //! Children
+
+    /// Gets the child nodes.
+    pub fn children(self) -> Vec<Node<'a>> {
+        match self {
+            Self::Statement(statement) => statement.children(),
+            Self::Statements(statements) => statements
+                .iter()
+                .map(|statement| Self::Statement(statement.as_ref()))
+                .collect(),
+            Self::Expression(expression) => expression.children(),
+            Self::Comments(_) => Vec::default(),
+        }
+    }
+}
+
+impl<'a> Node<'a> {
+    //! Walk
+
+    /// Walks the node and its descendants in pre-order.
+    pub fn walk(self, f: &mut dyn FnMut(Node<'a>)) {
+        f(self);
+        for child in self.children() {
+            child.walk(f);
+        }
+    }
+
+    /// Finds the statements and expressions of type `T` in the node and its descendants.
+    pub fn find_all<T: Any>(self) -> Vec<&'a T> {
+        let mut found: Vec<&'a T> = Vec::default();
+        self.walk(&mut |node| {
+            let element: Option<&'a T> = match node {
+                Self::Statement(statement) => statement.as_any().downcast_ref(),
+                Self::Expression(expression) => expression.as_any().downcast_ref(),
+                Self::Statements(_) | Self::Comments(_) => None,
+            };
+            found.extend(element);
+        });
+        found
+    }
+}
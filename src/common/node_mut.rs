@@ -0,0 +1,63 @@
+use crate::{Expression, Statement};
+use std::any::Any;
+
+/// A mutable node of a statement tree.
+///
+/// Statement lists and comment lines are exposed as vectors so that elements can be inserted,
+/// removed or replaced.
+pub enum NodeMut<'a> {
+    /// A statement.
+    Statement(&'a mut dyn Statement),
+
+    /// A list of statements.
+    Statements(&'a mut Vec<Box<dyn Statement>>),
+
+    /// An expression.
+    Expression(&'a mut dyn Expression),
+
+    /// The comment lines of an element.
+    Comments(&'a mut Vec<String>),
+}
+
+impl NodeMut<'_> {
+    //! Walk
+
+    /// Walks the node and its descendants in pre-order.
+    ///
+    /// The children of a node are visited after the node is transformed by `f`.
+    pub fn walk(mut self, f: &mut dyn FnMut(&mut NodeMut<'_>)) {
+        f(&mut self);
+        match self {
+            Self::Statement(statement) => {
+                for child in statement.children_mut() {
+                    child.walk(f);
+                }
+            }
+            Self::Statements(statements) => {
+                for statement in statements.iter_mut() {
+                    NodeMut::Statement(statement.as_mut()).walk(f);
+                }
+            }
+            Self::Expression(expression) => {
+                for child in expression.children_mut() {
+                    child.walk(f);
+                }
+            }
+            Self::Comments(_) => {}
+        }
+    }
+
+    /// Transforms the statements and expressions of type `T` in the node and its descendants.
+    pub fn for_each<T: Any>(self, f: &mut dyn FnMut(&mut T)) {
+        self.walk(&mut |node| {
+            let element: Option<&mut T> = match node {
+                NodeMut::Statement(statement) => statement.as_any_mut().downcast_mut(),
+                NodeMut::Expression(expression) => expression.as_any_mut().downcast_mut(),
+                NodeMut::Statements(_) | NodeMut::Comments(_) => None,
+            };
+            if let Some(element) = element {
+                f(element);
+            }
+        });
+    }
+}
@@ -1,7 +1,7 @@
-use crate::{CodeBuffer, Statement, WithStatements};
+use crate::{CodeBuffer, Node, NodeMut, Statement, WithStatements};
 
 /// Represents general source code.
-#[derive(Default)]
+#[derive(Clone, Debug, Default)]
 pub struct Source {
     statements: Vec<Box<dyn Statement>>,
 }
@@ -22,8 +22,51 @@ impl WithStatements for Source {
     }
 }
 
+#[cfg(feature = "parallel")]
+impl Source {
+    //! Parallel
+
+    /// Writes the statements to the buffer `b` at the indent `level` concurrently.
+    ///
+    /// The statements are split into one chunk per available thread, each chunk is written to a
+    /// fork of the buffer created on a scoped thread and the forks are appended in order. The
+    /// written code is the same as for `write`.
+    pub fn write_parallel(&self, b: &mut CodeBuffer, level: usize) {
+        let threads: usize = std::thread::available_parallelism().map_or(1, |n| n.get());
+        let chunk_size: usize = self.statements.len().div_ceil(threads).max(1);
+        let fork_fn = b.fork_fn();
+        let forks: Vec<crate::ForkParts> = std::thread::scope(|scope| {
+            let handles: Vec<std::thread::ScopedJoinHandle<crate::ForkParts>> = self
+                .statements
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    let fork_fn = &fork_fn;
+                    scope.spawn(move || {
+                        let mut b: CodeBuffer = fork_fn();
+                        chunk.iter().for_each(|s| s.write(&mut b, level));
+                        b.into_parts()
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|h| h.join().unwrap_or_else(|e| std::panic::resume_unwind(e)))
+                .collect()
+        });
+        forks.into_iter().for_each(|fork| b.append_parts(fork));
+    }
+}
+
 impl Statement for Source {
     fn write(&self, b: &mut CodeBuffer, level: usize) {
         self.statements.iter().for_each(|s| s.write(b, level))
     }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        vec![Node::Statements(&self.statements)]
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        vec![NodeMut::Statements(&mut self.statements)]
+    }
 }
@@ -0,0 +1,195 @@
+use std::ops::Range;
+
+/// A position in written code.
+///
+/// The `line` and `column` are zero-based and the `column` is counted in characters.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Position {
+    /// The byte offset.
+    pub byte: usize,
+
+    /// The line index.
+    pub line: usize,
+
+    /// The column index.
+    pub column: usize,
+}
+
+/// A named span of written code.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct SourceSpan {
+    kind: &'static str,
+    name: String,
+    parent: Option<usize>,
+    start: Position,
+    end: Position,
+}
+
+impl SourceSpan {
+    //! Properties
+
+    /// Gets the kind of element. (ex: `struct`)
+    pub fn kind(&self) -> &'static str {
+        self.kind
+    }
+
+    /// Gets the name of the element.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Gets the optional index of the enclosing span.
+    pub fn parent(&self) -> Option<usize> {
+        self.parent
+    }
+
+    /// Gets the start position.
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Gets the end position. (exclusive)
+    pub fn end(&self) -> Position {
+        self.end
+    }
+
+    /// Gets the byte range.
+    pub fn bytes(&self) -> Range<usize> {
+        self.start.byte..self.end.byte
+    }
+
+    /// Gets the line range. (a span ending with a line-ending ends at the start of the next line)
+    pub fn lines(&self) -> Range<usize> {
+        if self.end.column == 0 && self.end.line > self.start.line {
+            self.start.line..self.end.line
+        } else {
+            self.start.line..self.end.line + 1
+        }
+    }
+}
+
+/// Maps named spans of elements to their positions in written code.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+pub struct SourceMap {
+    spans: Vec<SourceSpan>,
+    open: Vec<usize>,
+}
+
+impl SourceMap {
+    //! Access
+
+    /// Gets the spans. (ordered by start position)
+    pub fn spans(&self) -> &[SourceSpan] {
+        self.spans.as_slice()
+    }
+
+    /// Gets the spans containing the `line`. (outermost first)
+    pub fn spans_at_line(&self, line: usize) -> impl Iterator<Item = &SourceSpan> {
+        self.spans
+            .iter()
+            .filter(move |span| span.lines().contains(&line))
+    }
+
+    /// Gets the innermost span containing the `line`.
+    pub fn innermost_at_line(&self, line: usize) -> Option<&SourceSpan> {
+        self.spans_at_line(line).last()
+    }
+
+    /// Gets the chain of spans from the span at the `index` to its outermost parent.
+    pub fn ancestry(&self, index: usize) -> impl Iterator<Item = &SourceSpan> {
+        let mut next: Option<usize> = Some(index);
+        std::iter::from_fn(move || {
+            let span: &SourceSpan = self.spans.get(next?)?;
+            next = span.parent;
+            Some(span)
+        })
+    }
+}
+
+impl SourceMap {
+    //! Recording
+
+    /// Opens a span of the `kind` with the `name` at the `position`.
+    pub(crate) fn open(&mut self, kind: &'static str, name: String, position: Position) {
+        self.open.push(self.spans.len());
+        self.spans.push(SourceSpan {
+            kind,
+            name,
+            parent: self.open.iter().rev().nth(1).copied(),
+            start: position,
+            end: position,
+        });
+    }
+
+    /// Closes the most recently opened span at the `position`.
+    pub(crate) fn close(&mut self, position: Position) {
+        if let Some(index) = self.open.pop() {
+            self.spans[index].end = position;
+        }
+    }
+
+    /// Shifts the positions at or after the `position` forward by one line of `bytes`.
+    ///
+    /// Span ends at the `position` remain before the inserted line.
+    pub(crate) fn shift(&mut self, position: Position, bytes: usize) {
+        let shift = |p: &mut Position| {
+            p.byte += bytes;
+            p.line += 1;
+        };
+        for span in self.spans.iter_mut().rev() {
+            if span.start.byte < position.byte && span.end.byte <= position.byte {
+                break;
+            }
+            if span.start.byte >= position.byte {
+                shift(&mut span.start);
+            }
+            if span.end.byte > position.byte {
+                shift(&mut span.end);
+            }
+        }
+    }
+
+    /// Clamps the positions after the `position` to the `position`.
+    pub(crate) fn clamp(&mut self, position: Position) {
+        for span in self.spans.iter_mut().rev() {
+            if span.start.byte <= position.byte && span.end.byte <= position.byte {
+                break;
+            }
+            if span.start.byte > position.byte {
+                span.start = position;
+            }
+            if span.end.byte > position.byte {
+                span.end = position;
+            }
+        }
+    }
+
+    /// Appends the closed spans of the `other` source map written at the `start` position.
+    pub(crate) fn append(&mut self, other: SourceMap, start: Position) {
+        let offset: usize = self.spans.len();
+        let parent: Option<usize> = self.open.last().copied();
+        let translate = |p: Position| Position {
+            byte: start.byte + p.byte,
+            line: start.line + p.line,
+            column: if p.line == 0 {
+                start.column + p.column
+            } else {
+                p.column
+            },
+        };
+        self.spans
+            .extend(other.spans.into_iter().map(|span| SourceSpan {
+                kind: span.kind,
+                name: span.name,
+                parent: span.parent.map(|p| p + offset).or(parent),
+                start: translate(span.start),
+                end: translate(span.end),
+            }));
+    }
+
+    /// Clears the spans.
+    pub(crate) fn clear(&mut self) {
+        self.spans.clear();
+        self.open.clear();
+    }
+}
@@ -0,0 +1,61 @@
+use std::fmt;
+use std::hash::Hasher;
+
+/// A 64-bit FNV-1a hasher with output that is stable across runs and builds.
+///
+/// The std `DefaultHasher` algorithm is unspecified and may change between Rust releases, so
+/// hashes that are persisted (embedded in files or cached between runs) use this hasher instead.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct StableHasher {
+    state: u64,
+}
+
+impl StableHasher {
+    //! Constants
+
+    /// The FNV-1a 64-bit offset basis.
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+    /// The FNV-1a 64-bit prime.
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+}
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self {
+            state: Self::OFFSET_BASIS,
+        }
+    }
+}
+
+impl StableHasher {
+    //! Hashing
+
+    /// Hashes the `text`.
+    pub fn hash_str(text: &str) -> u64 {
+        let mut hasher: Self = Self::default();
+        hasher.write(text.as_bytes());
+        hasher.finish()
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.state
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state ^= *byte as u64;
+            self.state = self.state.wrapping_mul(Self::PRIME);
+        }
+    }
+}
+
+impl fmt::Write for StableHasher {
+    /// Hashes the bytes of the formatted `text`. (for hashing `Debug` output without allocating)
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        Hasher::write(self, text.as_bytes());
+        Ok(())
+    }
+}
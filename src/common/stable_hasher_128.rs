@@ -0,0 +1,60 @@
+use std::fmt;
+use std::hash::Hasher;
+
+/// A 128-bit FNV-1a hasher with output that is stable across runs and builds.
+///
+/// This is the wide variant of the `StableHasher` for keys where a 64-bit collision would go
+/// unnoticed. (ex: render cache keys)
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct StableHasher128 {
+    state: u128,
+}
+
+impl StableHasher128 {
+    //! Constants
+
+    /// The FNV-1a 128-bit offset basis.
+    const OFFSET_BASIS: u128 = 0x6c62_272e_07bb_0142_62b8_2175_6295_c58d;
+
+    /// The FNV-1a 128-bit prime.
+    const PRIME: u128 = 0x0000_0000_0100_0000_0000_0000_0000_013b;
+}
+
+impl Default for StableHasher128 {
+    fn default() -> Self {
+        Self {
+            state: Self::OFFSET_BASIS,
+        }
+    }
+}
+
+impl StableHasher128 {
+    //! Hashing
+
+    /// Gets the 128-bit hash of the bytes written so far.
+    pub fn finish_128(&self) -> u128 {
+        self.state
+    }
+}
+
+impl Hasher for StableHasher128 {
+    /// Gets the 128-bit hash folded to 64 bits.
+    fn finish(&self) -> u64 {
+        (self.state ^ (self.state >> 64)) as u64
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state ^= *byte as u128;
+            self.state = self.state.wrapping_mul(Self::PRIME);
+        }
+    }
+}
+
+impl fmt::Write for StableHasher128 {
+    /// Hashes the bytes of the formatted `text`. (for hashing `Debug` output without allocating)
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        Hasher::write(self, text.as_bytes());
+        Ok(())
+    }
+}
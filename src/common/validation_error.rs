@@ -0,0 +1,53 @@
+use crate::ValidationErrorKind;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// An error validating a node of a code model.
+///
+/// The `path` locates the offending node as `(kind, name)` pairs from the outermost node inward.
+/// (ex: `[("struct", "Foo"), ("field", "a")]`, the kinds match the kinds of source spans)
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct ValidationError {
+    path: Vec<(&'static str, String)>,
+    kind: ValidationErrorKind,
+}
+
+impl ValidationError {
+    //! Construction
+
+    /// Creates a new validation error of the `kind` for the node at the `path`.
+    pub fn new(path: Vec<(&'static str, String)>, kind: ValidationErrorKind) -> Self {
+        Self { path, kind }
+    }
+}
+
+impl ValidationError {
+    //! Properties
+
+    /// Gets the path of the offending node. (outermost first)
+    pub fn path(&self) -> &[(&'static str, String)] {
+        self.path.as_slice()
+    }
+
+    /// Gets the kind of error.
+    pub fn kind(&self) -> &ValidationErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, (kind, name)) in self.path.iter().enumerate() {
+            if i != 0 {
+                write!(f, " > ")?;
+            }
+            write!(f, "{} {}", kind, name)?;
+        }
+        if !self.path.is_empty() {
+            write!(f, ": ")?;
+        }
+        write!(f, "{}", self.kind)
+    }
+}
+
+impl Error for ValidationError {}
@@ -0,0 +1,67 @@
+use std::fmt::{Display, Formatter};
+
+/// The kind of a validation error.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum ValidationErrorKind {
+    /// A name that is not a valid identifier.
+    InvalidIdentifier { name: String },
+
+    /// A name declared more than once. (the `kind` of element, ex: `field`)
+    Duplicate { kind: &'static str, name: String },
+
+    /// A generic type parameter that is declared but never used.
+    UnusedGeneric { name: String },
+
+    /// A lifetime parameter declared after a type or const parameter.
+    MisplacedLifetime { name: String },
+
+    /// A generic parameter without a default declared after a parameter with a default.
+    NonTrailingDefault { name: String },
+
+    /// A const generic parameter with bounds.
+    BoundedConst { name: String },
+
+    /// A generic parameter default where defaults are not allowed. (ex: impl blocks and functions)
+    UnexpectedDefault { name: String },
+
+    /// Two flags that cannot be combined. (ex: `const` and `async`)
+    ConflictingFlags {
+        first: &'static str,
+        second: &'static str,
+    },
+
+    /// Fields that do not match the `kind` of the element. (ex: tuple fields of a `named` struct)
+    MismatchedFields { kind: &'static str },
+
+    /// An element without the fields it requires. (ex: a union without fields)
+    MissingFields,
+}
+
+impl Display for ValidationErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidIdentifier { name } => write!(f, "invalid identifier: {:?}", name),
+            Self::Duplicate { kind, name } => write!(f, "duplicate {} `{}`", kind, name),
+            Self::UnusedGeneric { name } => write!(f, "unused generic `{}`", name),
+            Self::MisplacedLifetime { name } => write!(
+                f,
+                "lifetime `{}` must be declared before type and const generics",
+                name
+            ),
+            Self::NonTrailingDefault { name } => write!(
+                f,
+                "generic `{}` without a default follows a generic with a default",
+                name
+            ),
+            Self::BoundedConst { name } => write!(f, "const generic `{}` cannot have bounds", name),
+            Self::UnexpectedDefault { name } => {
+                write!(f, "generic `{}` cannot have a default here", name)
+            }
+            Self::ConflictingFlags { first, second } => {
+                write!(f, "conflicting flags `{}` and `{}`", first, second)
+            }
+            Self::MismatchedFields { kind } => write!(f, "fields do not match the {} kind", kind),
+            Self::MissingFields => write!(f, "missing fields"),
+        }
+    }
+}
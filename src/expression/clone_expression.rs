@@ -0,0 +1,21 @@
+use crate::Expression;
+
+/// Object-safe cloning of expressions.
+///
+/// This is implemented for every `Clone` expression so that `Box<dyn Expression>` is `Clone`.
+pub trait CloneExpression {
+    /// Clones the expression into a box.
+    fn clone_expression(&self) -> Box<dyn Expression>;
+}
+
+impl<T: 'static + Expression + Clone> CloneExpression for T {
+    fn clone_expression(&self) -> Box<dyn Expression> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn Expression> {
+    fn clone(&self) -> Self {
+        (**self).clone_expression()
+    }
+}
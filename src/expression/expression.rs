@@ -1,7 +1,49 @@
-use crate::CodeBuffer;
+use crate::{AsAny, CloneExpression, CodeBuffer, Doc, Node, NodeMut};
+use std::fmt::Debug;
 
 /// Code within a single line.
-pub trait Expression {
+///
+/// Implementors must be `Clone + Debug + Send + Sync + 'static`. (`AsAny` and `CloneExpression` are
+/// implemented for all such types)
+pub trait Expression: AsAny + CloneExpression + Debug + Send + Sync {
     /// Writes the code to the buffer `b`.
     fn write(&self, b: &mut CodeBuffer);
+
+    /// Converts the code to a document for width-aware layout.
+    ///
+    /// The default implementation writes the code flat as a single text document.
+    fn to_doc(&self) -> Doc {
+        let mut b: CodeBuffer = CodeBuffer::new("", "", 64);
+        self.write(&mut b);
+        Doc::from(String::from(b))
+    }
+
+    /// Gets the child nodes. (in write order)
+    fn children(&self) -> Vec<Node<'_>> {
+        Vec::default()
+    }
+
+    /// Gets the mutable child nodes. (in write order)
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        Vec::default()
+    }
+}
+
+impl dyn Expression {
+    //! Downcasting
+
+    /// Checks if the expression is of type `T`.
+    pub fn is<T: Expression>(&self) -> bool {
+        self.as_any().is::<T>()
+    }
+
+    /// Downcasts the expression to type `T`.
+    pub fn downcast_ref<T: Expression>(&self) -> Option<&T> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Downcasts the expression to the mutable type `T`.
+    pub fn downcast_mut<T: Expression>(&mut self) -> Option<&mut T> {
+        self.as_any_mut().downcast_mut()
+    }
 }
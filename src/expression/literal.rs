@@ -15,6 +15,15 @@ impl<S: Into<String>> From<S> for Literal {
     }
 }
 
+impl Literal {
+    //! Properties
+
+    /// Gets the literal value.
+    pub fn value(&self) -> &str {
+        self.value.as_str()
+    }
+}
+
 impl Expression for Literal {
     fn write(&self, b: &mut CodeBuffer) {
         b.write(self.value.as_str());
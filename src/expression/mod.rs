@@ -1,5 +1,11 @@
+pub use clone_expression::*;
 pub use expression::*;
 pub use literal::*;
+pub use string_literal::*;
+pub use template::*;
 
+mod clone_expression;
 mod expression;
 mod literal;
+mod string_literal;
+mod template;
@@ -0,0 +1,31 @@
+use crate::{CodeBuffer, Expression};
+
+/// A string literal expression. (quoted & escaped with the language of the code buffer)
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct StringLiteral {
+    value: String,
+}
+
+impl<S: Into<String>> From<S> for StringLiteral {
+    fn from(value: S) -> Self {
+        Self {
+            value: value.into(),
+        }
+    }
+}
+
+impl StringLiteral {
+    //! Properties
+
+    /// Gets the unescaped value.
+    pub fn value(&self) -> &str {
+        self.value.as_str()
+    }
+}
+
+impl Expression for StringLiteral {
+    fn write(&self, b: &mut CodeBuffer) {
+        let literal: String = b.language().string_literal(self.value.as_str());
+        b.write(literal.as_str());
+    }
+}
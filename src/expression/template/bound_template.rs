@@ -0,0 +1,51 @@
+use crate::{CodeBuffer, Doc, Expression, Node, NodeMut};
+
+/// A template with every placeholder bound to an expression.
+#[derive(Clone, Debug)]
+pub struct BoundTemplate {
+    parts: Vec<TemplatePart>,
+    bindings: Vec<Box<dyn Expression>>,
+}
+
+/// A part of a bound template.
+#[derive(Clone, Debug)]
+pub(crate) enum TemplatePart {
+    Text(String),
+    Binding(usize),
+}
+
+impl BoundTemplate {
+    //! Construction
+
+    /// Creates a new bound template. (the binding indices of the `parts` must be valid)
+    pub(crate) fn new(parts: Vec<TemplatePart>, bindings: Vec<Box<dyn Expression>>) -> Self {
+        Self { parts, bindings }
+    }
+}
+
+impl Expression for BoundTemplate {
+    fn write(&self, b: &mut CodeBuffer) {
+        b.write_doc(&self.to_doc());
+    }
+
+    fn to_doc(&self) -> Doc {
+        Doc::concat(self.parts.iter().map(|part| match part {
+            TemplatePart::Text(text) => Doc::from(text.as_str()),
+            TemplatePart::Binding(index) => self.bindings[*index].to_doc(),
+        }))
+    }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        self.bindings
+            .iter()
+            .map(|c| Node::Expression(c.as_ref()))
+            .collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        self.bindings
+            .iter_mut()
+            .map(|c| NodeMut::Expression(c.as_mut()))
+            .collect()
+    }
+}
@@ -0,0 +1,7 @@
+pub use bound_template::*;
+pub use template::*;
+pub use template_error::*;
+
+mod bound_template;
+mod template;
+mod template_error;
@@ -0,0 +1,243 @@
+use crate::{BoundTemplate, Error, Expression, Literal, TemplateError, TemplatePart};
+
+/// An expression template with named placeholders. (ex: `{name}.push({value})`)
+///
+/// Literal braces are escaped by doubling them. (`{{` and `}}`) A template is converted to an
+/// expression with `build` once every placeholder is bound.
+#[derive(Clone, Debug)]
+pub struct Template {
+    segments: Vec<Segment>,
+    bindings: Vec<(String, Box<dyn Expression>)>,
+}
+
+/// A parsed template segment.
+#[derive(Clone, Debug)]
+enum Segment {
+    Text(String),
+    Placeholder(String),
+}
+
+impl Template {
+    //! Construction
+
+    /// Parses the template `text`.
+    pub fn parse<S>(text: S) -> Result<Self, Error>
+    where
+        S: AsRef<str>,
+    {
+        let text: &str = text.as_ref();
+        let mut segments: Vec<Segment> = Vec::default();
+        let mut literal: String = String::default();
+        let mut chars = text.char_indices().peekable();
+        while let Some((offset, c)) = chars.next() {
+            match c {
+                '{' if chars.next_if(|(_, c)| *c == '{').is_some() => literal.push('{'),
+                '}' if chars.next_if(|(_, c)| *c == '}').is_some() => literal.push('}'),
+                '{' => {
+                    let end: usize = text[offset..]
+                        .find('}')
+                        .map(|end| offset + end)
+                        .ok_or(TemplateError::UnclosedPlaceholder { offset })?;
+                    let name: &str = &text[offset + 1..end];
+                    if !Self::is_placeholder_name(name) {
+                        return Err(TemplateError::InvalidPlaceholder {
+                            name: name.to_string(),
+                        }
+                        .into());
+                    }
+                    if !literal.is_empty() {
+                        segments.push(Segment::Text(std::mem::take(&mut literal)));
+                    }
+                    segments.push(Segment::Placeholder(name.to_string()));
+                    while chars.next_if(|(i, _)| *i <= end).is_some() {}
+                }
+                '}' => return Err(TemplateError::UnmatchedBrace { offset }.into()),
+                c => literal.push(c),
+            }
+        }
+        if !literal.is_empty() {
+            segments.push(Segment::Text(literal));
+        }
+        Ok(Self {
+            segments,
+            bindings: Vec::default(),
+        })
+    }
+
+    /// Checks if the `name` is a valid placeholder name. (an ASCII identifier)
+    fn is_placeholder_name(name: &str) -> bool {
+        let mut chars = name.chars();
+        chars
+            .next()
+            .is_some_and(|c| c == '_' || c.is_ascii_alphabetic())
+            && chars.all(|c| c == '_' || c.is_ascii_alphanumeric())
+    }
+}
+
+impl Template {
+    //! Placeholders
+
+    /// Gets the placeholder names in order of appearance. (repeated placeholders are repeated)
+    pub fn placeholders(&self) -> impl Iterator<Item = &str> {
+        self.segments.iter().filter_map(|segment| match segment {
+            Segment::Text(_) => None,
+            Segment::Placeholder(name) => Some(name.as_str()),
+        })
+    }
+}
+
+impl Template {
+    //! Bindings
+
+    /// Binds the placeholder `name` to the `expression`. (replaces an existing binding)
+    pub fn bind<S, E>(&mut self, name: S, expression: E)
+    where
+        S: Into<String>,
+        E: 'static + Expression,
+    {
+        let name: String = name.into();
+        let expression: Box<dyn Expression> = Box::new(expression);
+        match self.bindings.iter_mut().find(|(n, _)| *n == name) {
+            Some(binding) => binding.1 = expression,
+            None => self.bindings.push((name, expression)),
+        }
+    }
+
+    /// Binds the placeholder `name` to the `expression`. (replaces an existing binding)
+    pub fn with_binding<S, E>(mut self, name: S, expression: E) -> Self
+    where
+        S: Into<String>,
+        E: 'static + Expression,
+    {
+        self.bind(name, expression);
+        self
+    }
+
+    /// Binds the placeholder `name` to the `literal`. (replaces an existing binding)
+    pub fn bind_literal<S, L>(&mut self, name: S, literal: L)
+    where
+        S: Into<String>,
+        L: Into<Literal>,
+    {
+        self.bind(name, literal.into());
+    }
+
+    /// Binds the placeholder `name` to the `literal`. (replaces an existing binding)
+    pub fn with_literal<S, L>(self, name: S, literal: L) -> Self
+    where
+        S: Into<String>,
+        L: Into<Literal>,
+    {
+        self.with_binding(name, literal.into())
+    }
+}
+
+impl Template {
+    //! Build
+
+    /// Builds the bound template expression.
+    ///
+    /// Returns an error if a placeholder is unbound or a binding is unused.
+    pub fn build(self) -> Result<BoundTemplate, Error> {
+        if let Some((name, _)) = self
+            .bindings
+            .iter()
+            .find(|(name, _)| !self.placeholders().any(|p| p == name))
+        {
+            return Err(TemplateError::UnusedBinding { name: name.clone() }.into());
+        }
+        let mut parts: Vec<TemplatePart> = Vec::with_capacity(self.segments.len());
+        for segment in self.segments {
+            parts.push(match segment {
+                Segment::Text(text) => TemplatePart::Text(text),
+                Segment::Placeholder(name) => {
+                    match self.bindings.iter().position(|(n, _)| *n == name) {
+                        Some(index) => TemplatePart::Binding(index),
+                        None => return Err(TemplateError::UnboundPlaceholder { name }.into()),
+                    }
+                }
+            });
+        }
+        let bindings: Vec<Box<dyn Expression>> =
+            self.bindings.into_iter().map(|(_, e)| e).collect();
+        Ok(BoundTemplate::new(parts, bindings))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CodeBuffer, Error, Expression, Template, TemplateError};
+
+    /// Parses the template `text` and gets the template error.
+    fn parse_error(text: &str) -> TemplateError {
+        match Template::parse(text) {
+            Err(Error::Template(error)) => error,
+            result => panic!("expected a template error: {:?}", result),
+        }
+    }
+
+    /// Writes the bound `template`.
+    fn render(template: Template) -> String {
+        let mut b: CodeBuffer = CodeBuffer::new("", "\n", 64);
+        template.build().unwrap().write(&mut b);
+        String::from(b)
+    }
+
+    #[test]
+    fn parses_placeholders() {
+        let template: Template = Template::parse("{name}.push({value}, {name})").unwrap();
+        let placeholders: Vec<&str> = template.placeholders().collect();
+        assert_eq!(placeholders, ["name", "value", "name"]);
+        let template: Template = template
+            .with_literal("name", "list")
+            .with_literal("value", "1");
+        assert_eq!(render(template), "list.push(1, list)");
+    }
+
+    #[test]
+    fn unescapes_braces() {
+        let template: Template = Template::parse("{{{a}}}").unwrap().with_literal("a", "x");
+        assert_eq!(render(template), "{x}");
+    }
+
+    #[test]
+    fn rejects_invalid_templates() {
+        assert_eq!(
+            parse_error("a {b"),
+            TemplateError::UnclosedPlaceholder { offset: 2 }
+        );
+        assert_eq!(
+            parse_error("a}b"),
+            TemplateError::UnmatchedBrace { offset: 1 }
+        );
+        assert_eq!(
+            parse_error("{1a}"),
+            TemplateError::InvalidPlaceholder {
+                name: "1a".to_string()
+            }
+        );
+        assert_eq!(
+            parse_error("{}"),
+            TemplateError::InvalidPlaceholder {
+                name: String::default()
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_unbound_and_unused_bindings() {
+        let unbound: Template = Template::parse("{a}").unwrap();
+        assert!(matches!(
+            unbound.build(),
+            Err(Error::Template(TemplateError::UnboundPlaceholder { .. }))
+        ));
+        let unused: Template = Template::parse("{a}")
+            .unwrap()
+            .with_literal("a", "x")
+            .with_literal("b", "y");
+        assert!(matches!(
+            unused.build(),
+            Err(Error::Template(TemplateError::UnusedBinding { .. }))
+        ));
+    }
+}
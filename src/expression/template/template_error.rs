@@ -0,0 +1,43 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// An error parsing or binding a template.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum TemplateError {
+    /// A `{` without a closing `}`. (at the byte `offset`)
+    UnclosedPlaceholder { offset: usize },
+
+    /// A `}` without an opening `{`. (at the byte `offset`)
+    UnmatchedBrace { offset: usize },
+
+    /// A placeholder name that is not an identifier.
+    InvalidPlaceholder { name: String },
+
+    /// A placeholder without a binding.
+    UnboundPlaceholder { name: String },
+
+    /// A binding without a placeholder.
+    UnusedBinding { name: String },
+}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnclosedPlaceholder { offset } => {
+                write!(f, "unclosed template placeholder at byte {}", offset)
+            }
+            Self::UnmatchedBrace { offset } => {
+                write!(f, "unmatched template brace at byte {}", offset)
+            }
+            Self::InvalidPlaceholder { name } => {
+                write!(f, "invalid template placeholder name: {:?}", name)
+            }
+            Self::UnboundPlaceholder { name } => {
+                write!(f, "unbound template placeholder: {}", name)
+            }
+            Self::UnusedBinding { name } => write!(f, "unused template binding: {}", name),
+        }
+    }
+}
+
+impl Error for TemplateError {}
@@ -0,0 +1,33 @@
+use crate::{CommentSyntax, Language};
+
+/// A generic C-like language. (braces, semicolons & `//` comments)
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+pub struct CLikeLanguage {
+    _nothing: (),
+}
+
+impl Language for CLikeLanguage {
+    fn name(&self) -> &str {
+        "c-like"
+    }
+
+    fn block_open(&self) -> &str {
+        "{"
+    }
+
+    fn block_close(&self) -> Option<&str> {
+        Some("}")
+    }
+
+    fn statement_terminator(&self) -> &str {
+        ";"
+    }
+
+    fn comment_syntax(&self) -> CommentSyntax {
+        CommentSyntax::C_LIKE
+    }
+
+    fn keywords(&self) -> &[&str] {
+        &[]
+    }
+}
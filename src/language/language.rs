@@ -0,0 +1,90 @@
+use crate::CommentSyntax;
+
+/// The syntax conventions of a target language.
+///
+/// A language is carried by the code buffer so that generic statements can write blocks,
+/// statement terminators, comments, strings and identifiers for the target language.
+pub trait Language: Send + Sync {
+    /// Gets the name of the language.
+    fn name(&self) -> &str;
+
+    /// Gets the block open delimiter. (ex: `{` or `:`)
+    fn block_open(&self) -> &str;
+
+    /// Gets the optional block close delimiter. (ex: `}`, indentation-based languages have none)
+    fn block_close(&self) -> Option<&str>;
+
+    /// Gets the statement of an empty block. (ex: `pass`, empty if blocks can be empty)
+    fn empty_block(&self) -> &str {
+        ""
+    }
+
+    /// Gets the statement terminator. (ex: `;`)
+    fn statement_terminator(&self) -> &str;
+
+    /// Gets the comment syntax.
+    fn comment_syntax(&self) -> CommentSyntax;
+
+    /// Gets the reserved keywords.
+    fn keywords(&self) -> &[&str];
+
+    /// Checks if the `name` is a reserved keyword.
+    fn is_keyword(&self, name: &str) -> bool {
+        self.keywords().contains(&name)
+    }
+
+    /// Checks if the `name` is a valid identifier.
+    ///
+    /// By default an identifier starts with a letter or `_` followed by letters, digits or `_`
+    /// and is not a keyword.
+    fn is_identifier(&self, name: &str) -> bool {
+        let mut chars = name.chars();
+        chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+            && chars.all(|c| c.is_alphanumeric() || c == '_')
+            && !self.is_keyword(name)
+    }
+
+    /// Escapes the `keyword` into a valid identifier. (ex: `type_`)
+    fn escape_keyword(&self, keyword: &str) -> String {
+        format!("{}_", keyword)
+    }
+
+    /// Escapes the `name` into a valid identifier.
+    ///
+    /// Invalid characters are replaced with `_`, a name starting with a digit is prefixed with `_`
+    /// and a keyword is escaped with `escape_keyword`.
+    fn escape_identifier(&self, name: &str) -> String {
+        let mut result: String = name
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '_' })
+            .collect();
+        if result.is_empty() || result.starts_with(|c: char| c.is_ascii_digit()) {
+            result.insert(0, '_');
+        }
+        if self.is_keyword(result.as_str()) {
+            result = self.escape_keyword(result.as_str());
+        }
+        result
+    }
+
+    /// Writes the `value` as a quoted and escaped string literal.
+    ///
+    /// By default the value is double-quoted with backslash escapes and `\uXXXX` control chars.
+    fn string_literal(&self, value: &str) -> String {
+        let mut result: String = String::with_capacity(value.len() + 2);
+        result.push('"');
+        for c in value.chars() {
+            match c {
+                '"' => result.push_str("\\\""),
+                '\\' => result.push_str("\\\\"),
+                '\n' => result.push_str("\\n"),
+                '\r' => result.push_str("\\r"),
+                '\t' => result.push_str("\\t"),
+                c if c.is_control() => result.push_str(&format!("\\u{:04x}", c as u32)),
+                c => result.push(c),
+            }
+        }
+        result.push('"');
+        result
+    }
+}
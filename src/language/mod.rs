@@ -0,0 +1,7 @@
+pub use c_like_language::*;
+pub use language::*;
+pub use python_language::*;
+
+mod c_like_language;
+mod language;
+mod python_language;
@@ -0,0 +1,49 @@
+use crate::{CommentSyntax, Language};
+
+/// The Python language. (indentation-based blocks & `#` comments)
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+pub struct PythonLanguage {
+    _nothing: (),
+}
+
+impl PythonLanguage {
+    //! Constants
+
+    /// The reserved keywords.
+    pub const KEYWORDS: &'static [&'static str] = &[
+        "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class",
+        "continue", "def", "del", "elif", "else", "except", "finally", "for", "from", "global",
+        "if", "import", "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return",
+        "try", "while", "with", "yield",
+    ];
+}
+
+impl Language for PythonLanguage {
+    fn name(&self) -> &str {
+        "python"
+    }
+
+    fn block_open(&self) -> &str {
+        ":"
+    }
+
+    fn block_close(&self) -> Option<&str> {
+        None
+    }
+
+    fn empty_block(&self) -> &str {
+        "pass"
+    }
+
+    fn statement_terminator(&self) -> &str {
+        ""
+    }
+
+    fn comment_syntax(&self) -> CommentSyntax {
+        CommentSyntax::HASH
+    }
+
+    fn keywords(&self) -> &[&str] {
+        Self::KEYWORDS
+    }
+}
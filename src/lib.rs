@@ -2,11 +2,18 @@
 
 pub use common::*;
 pub use expression::*;
+pub use language::*;
+pub use output::*;
 pub use statement::*;
 
 mod common;
 mod expression;
+mod language;
+mod output;
 mod statement;
 
 #[cfg(feature = "rust")]
 pub mod rust;
+
+#[cfg(feature = "testing")]
+pub mod testing;
@@ -0,0 +1,24 @@
+/// The result of checking a generated file against the file on disk.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum FileCheck {
+    /// The file on disk matches the generated content.
+    Unchanged,
+
+    /// The file on disk differs from the generated content. (`diff` is a unified diff)
+    Modified { diff: String },
+
+    /// The file is not on disk.
+    Missing,
+
+    /// The file was generated previously but is no longer generated.
+    Extra,
+}
+
+impl FileCheck {
+    //! Status
+
+    /// Checks if the file on disk is up-to-date.
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, Self::Unchanged)
+    }
+}
@@ -0,0 +1,325 @@
+use std::fmt::Write;
+
+/// A line of a line diff.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum DiffLine<'a> {
+    /// A line in both the old and new text.
+    Equal(&'a str),
+
+    /// A line only in the old text.
+    Delete(&'a str),
+
+    /// A line only in the new text.
+    Insert(&'a str),
+}
+
+/// A line-based diff between an old and a new text. (lines include their line-endings)
+///
+/// The diff is computed with the linear space variant of the Myers algorithm. It is the shortest
+/// diff unless a part of the texts is more than `MAX_COST` edits from its middle, such a part is
+/// split at the furthest point reached to bound the time spent on heavily changed texts.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct LineDiff<'a> {
+    lines: Vec<DiffLine<'a>>,
+}
+
+impl LineDiff<'_> {
+    //! Constants
+
+    /// The max number of edits searched from either end of a part of the texts.
+    pub const MAX_COST: usize = 1024;
+}
+
+impl<'a> LineDiff<'a> {
+    //! Construction
+
+    /// Computes the line diff from the `old` text to the `new` text.
+    ///
+    /// The deleted lines of each change come before the inserted lines.
+    pub fn new(old: &'a str, new: &'a str) -> Self {
+        let old: Vec<&str> = old.split_inclusive('\n').collect();
+        let new: Vec<&str> = new.split_inclusive('\n').collect();
+        let mut lines: Vec<DiffLine> = Vec::with_capacity(old.len().max(new.len()));
+        Self::diff(&old, &new, &mut lines);
+        for run in lines
+            .chunk_by_mut(|a, b| matches!(a, DiffLine::Equal(_)) == matches!(b, DiffLine::Equal(_)))
+        {
+            run.sort_by_key(|line| matches!(line, DiffLine::Insert(_)));
+        }
+        Self { lines }
+    }
+
+    /// Appends the shortest edit script from `a` to `b` to the `lines`.
+    ///
+    /// The common prefix and suffix are stripped and the rest is split at its middle snake, so
+    /// each half has fewer edits than the whole.
+    fn diff(a: &[&'a str], b: &[&'a str], lines: &mut Vec<DiffLine<'a>>) {
+        let prefix: usize = a.iter().zip(b).take_while(|(a, b)| a == b).count();
+        let suffix: usize = a[prefix..]
+            .iter()
+            .rev()
+            .zip(b[prefix..].iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
+        lines.extend(a[..prefix].iter().map(|line| DiffLine::Equal(line)));
+        let (a_mid, b_mid) = (&a[prefix..a.len() - suffix], &b[prefix..b.len() - suffix]);
+        if a_mid.is_empty() {
+            lines.extend(b_mid.iter().map(|line| DiffLine::Insert(line)));
+        } else if b_mid.is_empty() {
+            lines.extend(a_mid.iter().map(|line| DiffLine::Delete(line)));
+        } else if let Some((x, y, u, v)) = Self::middle_snake(a_mid, b_mid) {
+            Self::diff(&a_mid[..x], &b_mid[..y], lines);
+            lines.extend(a_mid[x..u].iter().map(|line| DiffLine::Equal(line)));
+            Self::diff(&a_mid[u..], &b_mid[v..], lines);
+        } else {
+            lines.extend(a_mid.iter().map(|line| DiffLine::Delete(line)));
+            lines.extend(b_mid.iter().map(|line| DiffLine::Insert(line)));
+        }
+        lines.extend(a[a.len() - suffix..].iter().map(|l| DiffLine::Equal(l)));
+    }
+
+    /// Finds the middle snake `(x, y, u, v)` of the shortest edit script from `a` to `b`.
+    ///
+    /// The snake from `(x, y)` to `(u, v)` has the edits of the first half of the script before
+    /// it and the edits of the second half after it. (`a` and `b` are non-empty and differ)
+    ///
+    /// If the middle snake is more than `MAX_COST` edits from either end the furthest point reached
+    /// from the start is returned as an empty snake instead. (`None` if there is no such point)
+    fn middle_snake(a: &[&str], b: &[&str]) -> Option<(usize, usize, usize, usize)> {
+        let (n, m) = (a.len() as isize, b.len() as isize);
+        let delta: isize = n - m;
+        let odd: bool = delta % 2 != 0;
+        let max: isize = ((n + m + 1) / 2).min(Self::MAX_COST as isize);
+        let index = |k: isize| (k + max + 1) as usize;
+        let mut forward: Vec<isize> = vec![0; 2 * max as usize + 3];
+        let mut backward: Vec<isize> = vec![0; 2 * max as usize + 3];
+        backward[index(1)] = m;
+        for d in 0..=max {
+            for k in (-d..=d).step_by(2) {
+                let mut x: isize =
+                    if k == -d || (k != d && forward[index(k - 1)] < forward[index(k + 1)]) {
+                        forward[index(k + 1)]
+                    } else {
+                        forward[index(k - 1)] + 1
+                    };
+                let mut y: isize = x - k;
+                let (start_x, start_y) = (x, y);
+                while x < n && y < m && a[x as usize] == b[y as usize] {
+                    x += 1;
+                    y += 1;
+                }
+                forward[index(k)] = x;
+                let c: isize = k - delta;
+                if odd && c.abs() < d && y >= backward[index(c)] {
+                    return Some((start_x as usize, start_y as usize, x as usize, y as usize));
+                }
+            }
+            for c in (-d..=d).step_by(2) {
+                let mut y: isize =
+                    if c == -d || (c != d && backward[index(c - 1)] > backward[index(c + 1)]) {
+                        backward[index(c + 1)]
+                    } else {
+                        backward[index(c - 1)] - 1
+                    };
+                let k: isize = c + delta;
+                let mut x: isize = y + k;
+                let (end_x, end_y) = (x, y);
+                while x > 0 && y > 0 && a[x as usize - 1] == b[y as usize - 1] {
+                    x -= 1;
+                    y -= 1;
+                }
+                backward[index(c)] = y;
+                if !odd && k.abs() <= d && x <= forward[index(k)] {
+                    return Some((x as usize, y as usize, end_x as usize, end_y as usize));
+                }
+            }
+        }
+        (-max..=max)
+            .step_by(2)
+            .map(|k| (forward[index(k)], forward[index(k)] - k))
+            .filter(|(x, y)| *x <= n && 0 <= *y && *y <= m && 0 < x + y && x + y < n + m)
+            .max_by_key(|(x, y)| x + y)
+            .map(|(x, y)| (x as usize, y as usize, x as usize, y as usize))
+    }
+}
+
+impl<'a> LineDiff<'a> {
+    //! Access
+
+    /// Gets the diff lines.
+    pub fn lines(&self) -> &[DiffLine<'a>] {
+        self.lines.as_slice()
+    }
+
+    /// Checks if the texts differ.
+    pub fn has_changes(&self) -> bool {
+        self.lines
+            .iter()
+            .any(|line| !matches!(line, DiffLine::Equal(_)))
+    }
+}
+
+impl LineDiff<'_> {
+    //! Unified
+
+    /// Formats the diff in the unified format with `context` lines around each change.
+    pub fn unified(&self, old_name: &str, new_name: &str, context: usize) -> String {
+        let mut result: String = String::default();
+        if !self.has_changes() {
+            return result;
+        }
+        writeln!(result, "--- {}", old_name).unwrap();
+        writeln!(result, "+++ {}", new_name).unwrap();
+
+        let changes: Vec<usize> = (0..self.lines.len())
+            .filter(|i| !matches!(self.lines[*i], DiffLine::Equal(_)))
+            .collect();
+        let mut hunks: Vec<(usize, usize)> = Vec::default();
+        for change in changes {
+            let start: usize = change.saturating_sub(context);
+            let end: usize = (change + context + 1).min(self.lines.len());
+            match hunks.last_mut() {
+                Some(hunk) if start <= hunk.1 => hunk.1 = end,
+                _ => hunks.push((start, end)),
+            }
+        }
+
+        let (mut old_line, mut new_line, mut next) = (1usize, 1usize, 0usize);
+        for (start, end) in hunks {
+            for line in &self.lines[next..start] {
+                match line {
+                    DiffLine::Equal(_) => {
+                        old_line += 1;
+                        new_line += 1;
+                    }
+                    DiffLine::Delete(_) => old_line += 1,
+                    DiffLine::Insert(_) => new_line += 1,
+                }
+            }
+            let hunk: &[DiffLine] = &self.lines[start..end];
+            let old_count: usize = hunk
+                .iter()
+                .filter(|l| !matches!(l, DiffLine::Insert(_)))
+                .count();
+            let new_count: usize = hunk
+                .iter()
+                .filter(|l| !matches!(l, DiffLine::Delete(_)))
+                .count();
+            let old_start: usize = if old_count == 0 {
+                old_line - 1
+            } else {
+                old_line
+            };
+            let new_start: usize = if new_count == 0 {
+                new_line - 1
+            } else {
+                new_line
+            };
+            writeln!(
+                result,
+                "@@ -{},{} +{},{} @@",
+                old_start, old_count, new_start, new_count
+            )
+            .unwrap();
+            for line in hunk {
+                let (prefix, text) = match line {
+                    DiffLine::Equal(text) => (' ', text),
+                    DiffLine::Delete(text) => ('-', text),
+                    DiffLine::Insert(text) => ('+', text),
+                };
+                result.push(prefix);
+                result.push_str(text);
+                if !text.ends_with('\n') {
+                    result.push_str("\n\\ No newline at end of file\n");
+                }
+            }
+            old_line += old_count;
+            new_line += new_count;
+            next = end;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DiffLine, LineDiff};
+
+    /// Rebuilds the old and new texts from the `diff`.
+    fn texts(diff: &LineDiff) -> (String, String) {
+        let (mut old, mut new) = (String::default(), String::default());
+        for line in diff.lines() {
+            match line {
+                DiffLine::Equal(text) => {
+                    old.push_str(text);
+                    new.push_str(text);
+                }
+                DiffLine::Delete(text) => old.push_str(text),
+                DiffLine::Insert(text) => new.push_str(text),
+            }
+        }
+        (old, new)
+    }
+
+    #[test]
+    fn equal_texts() {
+        let diff: LineDiff = LineDiff::new("a\nb\n", "a\nb\n");
+        assert!(!diff.has_changes());
+        assert_eq!(diff.unified("old", "new", 3), "");
+    }
+
+    #[test]
+    fn deletes_before_inserts() {
+        let diff: LineDiff = LineDiff::new("a\nb\nc\n", "a\nx\ny\nc\n");
+        assert_eq!(
+            diff.lines(),
+            &[
+                DiffLine::Equal("a\n"),
+                DiffLine::Delete("b\n"),
+                DiffLine::Insert("x\n"),
+                DiffLine::Insert("y\n"),
+                DiffLine::Equal("c\n"),
+            ]
+        );
+    }
+
+    #[test]
+    fn shortest_diff() {
+        let diff: LineDiff = LineDiff::new("a\nb\nc\na\nb\nb\na\n", "c\nb\na\nb\na\nc\n");
+        let edits: usize = diff
+            .lines()
+            .iter()
+            .filter(|l| !matches!(l, DiffLine::Equal(_)))
+            .count();
+        assert_eq!(edits, 5);
+        assert_eq!(
+            texts(&diff),
+            ("a\nb\nc\na\nb\nb\na\n".into(), "c\nb\na\nb\na\nc\n".into())
+        );
+    }
+
+    #[test]
+    fn bounds_heavily_changed_texts() {
+        let old: String = (0..3000).map(|i| format!("{}\n", i)).collect();
+        let new: String = (0..3000).map(|i| format!("{}\n", i * 7 % 3001)).collect();
+        let diff: LineDiff = LineDiff::new(&old, &new);
+        assert_eq!(texts(&diff), (old.clone(), new.clone()));
+    }
+
+    #[test]
+    fn unified_hunks() {
+        let old: &str = "1\n2\n3\n4\n5\n6\n7\n8\n9\n";
+        let new: &str = "1\n2\nx\n4\n5\n6\n7\n8\n";
+        let expected: &str = "--- old\n+++ new\n\
+            @@ -2,3 +2,3 @@\n 2\n-3\n+x\n 4\n\
+            @@ -8,2 +8,1 @@\n 8\n-9\n";
+        assert_eq!(LineDiff::new(old, new).unified("old", "new", 1), expected);
+    }
+
+    #[test]
+    fn unified_missing_newline() {
+        let expected: &str =
+            "--- old\n+++ new\n@@ -1,1 +1,1 @@\n-a\n+a\n\\ No newline at end of file\n";
+        assert_eq!(LineDiff::new("a\n", "a").unified("old", "new", 3), expected);
+    }
+}
@@ -0,0 +1,15 @@
+pub use file_check::*;
+pub use line_diff::*;
+pub use output_tree::*;
+pub use protected_region_error::*;
+pub use protected_regions::*;
+pub use render_cache::*;
+pub use write_action::*;
+
+mod file_check;
+mod line_diff;
+mod output_tree;
+mod protected_region_error;
+mod protected_regions;
+mod render_cache;
+mod write_action;
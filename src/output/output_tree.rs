@@ -0,0 +1,411 @@
+use crate::{
+    CodeBuffer, Error, FileCheck, Generated, GeneratedStatus, LineDiff, ProtectedRegions,
+    RegionSplice, Statement, WriteAction,
+};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+/// A tree of generated files.
+///
+/// Each file maps a relative path to the root statement of the file. Every file is rendered with
+/// a fork of the same code buffer configuration.
+///
+/// # Stale Files
+/// Every write records the paths of the written files in a manifest file in the root directory.
+/// When stale file deletion is enabled the files recorded by a previous write that are no longer in
+/// the tree are deleted, along with any directories left empty.
+///
+/// # Protected Regions
+/// When region preservation is enabled the content of the protected regions of the existing files
+/// is spliced into the rendered files. Writing fails if a region of an existing file would be
+/// orphaned by the rendered file.
+///
+/// # Hand-Edits
+/// Writing fails if an existing file with a `Generated` banner was edited since it was generated,
+/// unless forced. This applies to both rewritten and deleted stale files.
+#[derive(Clone, Debug)]
+pub struct OutputTree {
+    buffer: CodeBuffer<'static>,
+    delete_stale: bool,
+    preserve_regions: bool,
+    force: bool,
+    files: BTreeMap<PathBuf, Box<dyn Statement>>,
+}
+
+impl OutputTree {
+    //! Constants
+
+    /// The name of the manifest file of generated paths.
+    pub const MANIFEST_FILE_NAME: &'static str = ".code-gen-manifest";
+
+    /// The number of context lines in the diffs of modified files.
+    pub const DIFF_CONTEXT: usize = 3;
+}
+
+impl From<CodeBuffer<'_>> for OutputTree {
+    /// Creates an output tree rendering the files with forks of the `buffer`.
+    fn from(buffer: CodeBuffer) -> Self {
+        Self {
+            buffer: buffer.fork(),
+            delete_stale: false,
+            preserve_regions: false,
+            force: false,
+            files: BTreeMap::default(),
+        }
+    }
+}
+
+impl Default for OutputTree {
+    fn default() -> Self {
+        Self::from(CodeBuffer::default())
+    }
+}
+
+impl OutputTree {
+    //! Stale Files
+
+    /// Sets the `delete_stale` flag.
+    pub fn set_delete_stale(&mut self, delete_stale: bool) {
+        self.delete_stale = delete_stale;
+    }
+
+    /// Sets the `delete_stale` flag.
+    pub fn with_delete_stale(mut self, delete_stale: bool) -> Self {
+        self.set_delete_stale(delete_stale);
+        self
+    }
+}
+
+impl OutputTree {
+    //! Protected Regions
+
+    /// Sets the `preserve_regions` flag.
+    pub fn set_preserve_regions(&mut self, preserve_regions: bool) {
+        self.preserve_regions = preserve_regions;
+    }
+
+    /// Sets the `preserve_regions` flag.
+    pub fn with_preserve_regions(mut self, preserve_regions: bool) -> Self {
+        self.set_preserve_regions(preserve_regions);
+        self
+    }
+
+    /// Splices the protected regions of the existing file at the `path` into the `rendered` code.
+    fn splice_regions(&self, path: &Path, rendered: String) -> Result<RegionSplice, Error> {
+        if !self.preserve_regions {
+            return Ok(RegionSplice {
+                code: rendered,
+                orphaned: Vec::default(),
+            });
+        }
+        let existing: String = match fs::read_to_string(path) {
+            Ok(existing) => existing,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => String::default(),
+            Err(error) => return Err(error.into()),
+        };
+        let regions: ProtectedRegions = ProtectedRegions::extract(existing.as_str())?;
+        Ok(regions.splice(rendered.as_str()))
+    }
+}
+
+impl OutputTree {
+    //! Hand-Edits
+
+    /// Sets the `force` flag. (overwrites hand-edited files)
+    pub fn set_force(&mut self, force: bool) {
+        self.force = force;
+    }
+
+    /// Sets the `force` flag. (overwrites hand-edited files)
+    pub fn with_force(mut self, force: bool) -> Self {
+        self.set_force(force);
+        self
+    }
+
+    /// Checks that the existing file at the `path` was not hand-edited, unless forced.
+    fn check_not_edited(&self, path: &Path) -> Result<(), Error> {
+        if self.force {
+            return Ok(());
+        }
+        let existing: Vec<u8> = match fs::read(path) {
+            Ok(existing) => existing,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(error) => return Err(error.into()),
+        };
+        match Generated::verify(String::from_utf8_lossy(&existing).as_ref()) {
+            GeneratedStatus::Edited => Err(Error::HandEdited(path.to_path_buf())),
+            GeneratedStatus::Untouched | GeneratedStatus::NotGenerated => Ok(()),
+        }
+    }
+}
+
+impl OutputTree {
+    //! Files
+
+    /// Gets the relative file paths.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.files.keys().map(PathBuf::as_path)
+    }
+
+    /// Adds the file at the relative `path` with the root `statement`. (replaces an existing file)
+    pub fn add_file<P, S>(&mut self, path: P, statement: S)
+    where
+        P: Into<PathBuf>,
+        S: 'static + Statement,
+    {
+        self.add_boxed_file(path, Box::new(statement));
+    }
+
+    /// Adds the file at the relative `path` with the root `statement`. (replaces an existing file)
+    pub fn with_file<P, S>(mut self, path: P, statement: S) -> Self
+    where
+        P: Into<PathBuf>,
+        S: 'static + Statement,
+    {
+        self.add_file(path, statement);
+        self
+    }
+
+    /// Adds the file at the relative `path` with the boxed root `statement`.
+    pub fn add_boxed_file<P>(&mut self, path: P, statement: Box<dyn Statement>)
+    where
+        P: Into<PathBuf>,
+    {
+        self.files.insert(path.into(), statement);
+    }
+}
+
+impl OutputTree {
+    //! Render
+
+    /// Renders the `statement` with a fork of the buffer configuration.
+    pub fn render_statement(&self, statement: &dyn Statement) -> String {
+        let mut b: CodeBuffer = self.buffer.fork();
+        statement.write(&mut b, 0);
+        b.into()
+    }
+
+    /// Renders the files.
+    pub fn render(&self) -> BTreeMap<&Path, String> {
+        self.files
+            .iter()
+            .map(|(path, statement)| (path.as_path(), self.render_statement(statement.as_ref())))
+            .collect()
+    }
+}
+
+impl OutputTree {
+    //! Write
+
+    /// Writes the files to the `root` directory.
+    ///
+    /// Files are only rewritten if their content changed. Returns the action taken for each path.
+    /// Fails before writing or deleting any file if a rewritten or stale file was hand-edited,
+    /// unless forced, or if a protected region would be orphaned.
+    pub fn write<P>(&self, root: P) -> Result<Vec<(PathBuf, WriteAction)>, Error>
+    where
+        P: AsRef<Path>,
+    {
+        let root: &Path = root.as_ref();
+        for path in self.files.keys() {
+            Self::check_relative(path)?;
+            self.check_not_edited(&root.join(path))?;
+        }
+        let stale: Vec<PathBuf> = if self.delete_stale {
+            Self::read_manifest(root)?
+                .into_iter()
+                .filter(|path| !self.files.contains_key(path) && Self::check_relative(path).is_ok())
+                .collect()
+        } else {
+            Vec::default()
+        };
+        for path in &stale {
+            self.check_not_edited(&root.join(path))?;
+        }
+        let mut files: Vec<(&Path, String)> = Vec::with_capacity(self.files.len());
+        for (path, code) in self.render() {
+            let file: PathBuf = root.join(path);
+            let splice: RegionSplice = self.splice_regions(&file, code)?;
+            if !splice.orphaned.is_empty() {
+                return Err(Error::OrphanedRegions {
+                    path: file,
+                    ids: splice.orphaned,
+                });
+            }
+            files.push((path, splice.code));
+        }
+
+        let mut actions: Vec<(PathBuf, WriteAction)> = Vec::with_capacity(self.files.len());
+        for (path, code) in files {
+            let action: WriteAction = Self::write_file(&root.join(path), code.as_bytes())?;
+            actions.push((path.to_path_buf(), action));
+        }
+        for path in stale {
+            Self::delete_file(root, &path)?;
+            actions.push((path, WriteAction::Deleted));
+        }
+        let mut manifest: String = String::default();
+        for path in self.files.keys() {
+            manifest.push_str(&path.to_string_lossy().replace('\\', "/"));
+            manifest.push('\n');
+        }
+        Self::write_file(&root.join(Self::MANIFEST_FILE_NAME), manifest.as_bytes())?;
+        Ok(actions)
+    }
+
+    /// Checks that the `path` is a relative path within the root directory.
+    pub(crate) fn check_relative(path: &Path) -> Result<(), Error> {
+        let valid: bool = path.components().all(|c| matches!(c, Component::Normal(_)));
+        if valid && path.components().next().is_some() {
+            Ok(())
+        } else {
+            Err(Error::InvalidPath(path.to_path_buf()))
+        }
+    }
+
+    /// Writes the `content` to the file at the `path` if it changed.
+    fn write_file(path: &Path, content: &[u8]) -> io::Result<WriteAction> {
+        let action: WriteAction = match fs::read(path) {
+            Ok(existing) if existing == content => return Ok(WriteAction::Unchanged),
+            Ok(_) => WriteAction::Updated,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => WriteAction::Created,
+            Err(error) => return Err(error),
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, content)?;
+        Ok(action)
+    }
+
+    /// Reads the paths recorded in the manifest file of the `root` directory.
+    pub(crate) fn read_manifest(root: &Path) -> io::Result<BTreeSet<PathBuf>> {
+        match fs::read_to_string(root.join(Self::MANIFEST_FILE_NAME)) {
+            Ok(manifest) => Ok(manifest
+                .lines()
+                .filter(|line| !line.is_empty())
+                .map(PathBuf::from)
+                .collect()),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(BTreeSet::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Deletes the file at the relative `path` and the directories left empty.
+    fn delete_file(root: &Path, path: &Path) -> io::Result<()> {
+        match fs::remove_file(root.join(path)) {
+            Ok(()) => {}
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+            Err(error) => return Err(error),
+        }
+        let mut parent: Option<&Path> = path.parent();
+        while let Some(dir) = parent.filter(|dir| !dir.as_os_str().is_empty()) {
+            let empty: bool = fs::read_dir(root.join(dir))
+                .map(|mut entries| entries.next().is_none())
+                .unwrap_or(false);
+            if !empty {
+                break;
+            }
+            fs::remove_dir(root.join(dir))?;
+            parent = dir.parent();
+        }
+        Ok(())
+    }
+}
+
+impl OutputTree {
+    //! Check
+
+    /// Checks the files in the `root` directory against the rendered files without writing.
+    ///
+    /// Files recorded in the manifest file by the last write that are no longer in the tree are
+    /// reported as extra.
+    pub fn check<P>(&self, root: P) -> Result<Vec<(PathBuf, FileCheck)>, Error>
+    where
+        P: AsRef<Path>,
+    {
+        let root: &Path = root.as_ref();
+        for path in self.files.keys() {
+            Self::check_relative(path)?;
+        }
+        let mut checks: Vec<(PathBuf, FileCheck)> = Vec::with_capacity(self.files.len());
+        for (path, code) in self.render() {
+            let file: PathBuf = root.join(path);
+            let code: String = self.splice_regions(&file, code)?.code;
+            let check: FileCheck = Self::check_content(&file, code.as_str())?;
+            checks.push((path.to_path_buf(), check));
+        }
+        for path in Self::read_manifest(root)? {
+            if !self.files.contains_key(&path) && root.join(&path).exists() {
+                checks.push((path, FileCheck::Extra));
+            }
+        }
+        Ok(checks)
+    }
+
+    /// Checks the file at the `path` against the rendered `statement`.
+    pub fn check_file<P>(&self, path: P, statement: &dyn Statement) -> Result<FileCheck, Error>
+    where
+        P: AsRef<Path>,
+    {
+        let path: &Path = path.as_ref();
+        let code: String = self
+            .splice_regions(path, self.render_statement(statement))?
+            .code;
+        Ok(Self::check_content(path, code.as_str())?)
+    }
+
+    /// Checks the file at the `path` against the generated `content`.
+    fn check_content(path: &Path, content: &str) -> io::Result<FileCheck> {
+        let existing: Vec<u8> = match fs::read(path) {
+            Ok(existing) => existing,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(FileCheck::Missing),
+            Err(error) => return Err(error),
+        };
+        if existing == content.as_bytes() {
+            Ok(FileCheck::Unchanged)
+        } else {
+            let existing: Cow<str> = String::from_utf8_lossy(&existing);
+            let name: String = path.display().to_string();
+            let diff: String = LineDiff::new(existing.as_ref(), content).unified(
+                name.as_str(),
+                name.as_str(),
+                Self::DIFF_CONTEXT,
+            );
+            Ok(FileCheck::Modified { diff })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FileCheck, Literal, OutputTree, Semi};
+    use std::path::PathBuf;
+
+    #[test]
+    fn check_reports_extra_files_without_stale_deletion() {
+        let root: PathBuf =
+            std::env::temp_dir().join(format!("code-gen-output-tree-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        OutputTree::default()
+            .with_file("a.rs", Semi::from(Literal::from("a")))
+            .with_file("b.rs", Semi::from(Literal::from("b")))
+            .write(&root)
+            .unwrap();
+        let checks: Vec<(PathBuf, FileCheck)> = OutputTree::default()
+            .with_file("a.rs", Semi::from(Literal::from("a")))
+            .check(&root)
+            .unwrap();
+        std::fs::remove_dir_all(&root).unwrap();
+        assert_eq!(
+            checks,
+            vec![
+                (PathBuf::from("a.rs"), FileCheck::Unchanged),
+                (PathBuf::from("b.rs"), FileCheck::Extra),
+            ]
+        );
+    }
+}
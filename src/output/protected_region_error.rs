@@ -0,0 +1,39 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// An error extracting the protected regions of previously generated code.
+///
+/// The `line` is the one-based line number of the offending marker.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum ProtectedRegionError {
+    /// A begin marker without a matching end marker.
+    Unterminated { id: String, line: usize },
+
+    /// An end marker without a matching begin marker.
+    Unmatched { id: String, line: usize },
+
+    /// A region id that appears more than once.
+    Duplicate { id: String, line: usize },
+}
+
+impl Display for ProtectedRegionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unterminated { id, line } => {
+                write!(f, "unterminated protected region `{}` at line {}", id, line)
+            }
+            Self::Unmatched { id, line } => {
+                write!(
+                    f,
+                    "unmatched protected region end `{}` at line {}",
+                    id, line
+                )
+            }
+            Self::Duplicate { id, line } => {
+                write!(f, "duplicate protected region `{}` at line {}", id, line)
+            }
+        }
+    }
+}
+
+impl Error for ProtectedRegionError {}
@@ -0,0 +1,143 @@
+use crate::{ProtectedRegion, ProtectedRegionError};
+use std::collections::BTreeMap;
+
+/// The content of the protected regions of previously generated code.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+pub struct ProtectedRegions {
+    regions: BTreeMap<String, String>,
+}
+
+/// The regenerated code with the protected regions spliced back in.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct RegionSplice {
+    /// The spliced code.
+    pub code: String,
+
+    /// The ids of the regions that no longer appear in the regenerated code.
+    pub orphaned: Vec<String>,
+}
+
+impl ProtectedRegions {
+    //! Extraction
+
+    /// Extracts the content between the matching markers of the previously generated `code`.
+    pub fn extract(code: &str) -> Result<Self, ProtectedRegionError> {
+        let mut regions: BTreeMap<String, String> = BTreeMap::default();
+        let mut open: Option<(&str, usize, String)> = None;
+        for (index, line) in code.split_inclusive('\n').enumerate() {
+            match Self::marker(line) {
+                Some((true, id)) => {
+                    if let Some((id, line, _)) = open {
+                        let id: String = id.to_string();
+                        return Err(ProtectedRegionError::Unterminated { id, line });
+                    }
+                    if regions.contains_key(id) {
+                        let id: String = id.to_string();
+                        return Err(ProtectedRegionError::Duplicate {
+                            id,
+                            line: index + 1,
+                        });
+                    }
+                    open = Some((id, index + 1, String::default()));
+                }
+                Some((false, id)) => match open.take() {
+                    Some((open_id, _, content)) if open_id == id => {
+                        regions.insert(id.to_string(), content);
+                    }
+                    _ => {
+                        let id: String = id.to_string();
+                        return Err(ProtectedRegionError::Unmatched {
+                            id,
+                            line: index + 1,
+                        });
+                    }
+                },
+                None => {
+                    if let Some((_, _, content)) = &mut open {
+                        content.push_str(line);
+                    }
+                }
+            }
+        }
+        match open {
+            Some((id, line, _)) => Err(ProtectedRegionError::Unterminated {
+                id: id.to_string(),
+                line,
+            }),
+            None => Ok(Self { regions }),
+        }
+    }
+
+    /// Parses the optional marker of the `line`. (`true` for begin markers)
+    pub(crate) fn marker(line: &str) -> Option<(bool, &str)> {
+        [
+            (true, ProtectedRegion::BEGIN_MARKER),
+            (false, ProtectedRegion::END_MARKER),
+        ]
+        .into_iter()
+        .find_map(|(begin, marker)| {
+            let rest: &str = &line[line.find(marker)? + marker.len()..];
+            if !rest.starts_with(char::is_whitespace) {
+                return None;
+            }
+            rest.split_whitespace().next().map(|id| (begin, id))
+        })
+    }
+}
+
+impl ProtectedRegions {
+    //! Access
+
+    /// Gets the content of the region with the `id`.
+    pub fn get(&self, id: &str) -> Option<&str> {
+        self.regions.get(id).map(String::as_str)
+    }
+
+    /// Gets the region ids.
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.regions.keys().map(String::as_str)
+    }
+
+    /// Checks if there are no regions.
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+}
+
+impl ProtectedRegions {
+    //! Splice
+
+    /// Splices the region content into the matching regions of the newly `rendered` code.
+    pub fn splice(&self, rendered: &str) -> RegionSplice {
+        let mut code: String = String::with_capacity(rendered.len());
+        let mut spliced: Vec<&str> = Vec::with_capacity(self.regions.len());
+        let mut skipping: bool = false;
+        for line in rendered.split_inclusive('\n') {
+            match Self::marker(line) {
+                Some((true, id)) => {
+                    code.push_str(line);
+                    if let Some(content) = self.regions.get(id) {
+                        code.push_str(content);
+                        spliced.push(id);
+                        skipping = true;
+                    }
+                }
+                Some((false, _)) => {
+                    code.push_str(line);
+                    skipping = false;
+                }
+                None => {
+                    if !skipping {
+                        code.push_str(line);
+                    }
+                }
+            }
+        }
+        let orphaned: Vec<String> = self
+            .ids()
+            .filter(|id| !spliced.contains(id))
+            .map(str::to_string)
+            .collect();
+        RegionSplice { code, orphaned }
+    }
+}
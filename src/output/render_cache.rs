@@ -0,0 +1,193 @@
+use crate::{CodeBuffer, Source, StableHasher128, Statement, WithStatements};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use std::hash::Hash;
+use std::io;
+use std::path::Path;
+
+/// A cache of rendered code reused between generation runs.
+///
+/// Items are keyed by a stable 128-bit hash of their model, their type, the indent level, the
+/// buffer configuration, the crate version and the version of the compiler that built the crate.
+/// (the std `Hash` and `Debug` impls may change between compiler versions) An unchanged item is
+/// written from the cache instead of being rendered again. The written code is the same as for
+/// rendering the item directly, except that buffers recording a source map bypass the cache so the
+/// spans of every item are recorded.
+///
+/// # Cache File
+/// The cache file starts with the `HEADER` line followed by the entries. Each entry is a line with
+/// the hex key and the byte length of the code, followed by the code and a line-ending. Only the
+/// entries used since the cache was loaded are saved, so entries of removed items are dropped.
+#[derive(Clone, Debug, Default)]
+pub struct RenderCache {
+    entries: HashMap<u128, String>,
+    used: HashSet<u128>,
+    hits: usize,
+    misses: usize,
+}
+
+impl RenderCache {
+    //! Constants
+
+    /// The header line of the cache file. (files with another header are ignored)
+    pub const HEADER: &'static str = "code-gen render cache v2";
+}
+
+impl RenderCache {
+    //! Persistence
+
+    /// Loads the cache from the file at the `path`.
+    ///
+    /// A missing file or a file of another cache version loads an empty cache.
+    pub fn load<P>(path: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let content: String = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(error) => return Err(error),
+        };
+        let mut cache: Self = Self::default();
+        let mut rest: &str = match content.strip_prefix(Self::HEADER) {
+            Some(rest) if rest.starts_with('\n') => &rest[1..],
+            _ => return Ok(cache),
+        };
+        while !rest.is_empty() {
+            let (key, code, next) =
+                Self::parse_entry(rest).ok_or_else(|| Self::corrupt(content.len() - rest.len()))?;
+            cache.entries.insert(key, code.to_string());
+            rest = next;
+        }
+        Ok(cache)
+    }
+
+    /// Parses the entry at the start of the `text` as `(key, code, rest)`.
+    fn parse_entry(text: &str) -> Option<(u128, &str, &str)> {
+        let (line, rest) = text.split_once('\n')?;
+        let (key, len) = line.split_once(' ')?;
+        let key: u128 = u128::from_str_radix(key, 16).ok()?;
+        let len: usize = len.parse().ok()?;
+        let code: &str = rest.get(..len)?;
+        let rest: &str = rest[len..].strip_prefix('\n')?;
+        Some((key, code, rest))
+    }
+
+    /// Creates the error for a corrupt cache file at the byte `offset`.
+    fn corrupt(offset: usize) -> io::Error {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("corrupt render cache entry at byte {}", offset),
+        )
+    }
+
+    /// Saves the entries used since the cache was loaded to the file at the `path`.
+    pub fn save<P>(&self, path: P) -> io::Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        let mut keys: Vec<u128> = self.used.iter().copied().collect();
+        keys.sort_unstable();
+        let mut content: String = format!("{}\n", Self::HEADER);
+        for key in keys {
+            let code: &str = self.entries[&key].as_str();
+            writeln!(content, "{:032x} {}", key, code.len()).unwrap();
+            content.push_str(code);
+            content.push('\n');
+        }
+        if let Some(parent) = path.as_ref().parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, content)
+    }
+}
+
+impl RenderCache {
+    //! Statistics
+
+    /// Gets the number of items written from the cache.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Gets the number of items rendered and added to the cache.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Gets the number of cached entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks if the cache has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl RenderCache {
+    //! Rendering
+
+    /// Writes the `item` to the buffer `b` at the indent `level`. (keyed by the `Hash` of the item)
+    pub fn render<T>(&mut self, item: &T, b: &mut CodeBuffer, level: usize)
+    where
+        T: Statement + Hash,
+    {
+        let key: u128 = Self::key(b, level, std::any::type_name::<T>(), |h| item.hash(h));
+        self.render_keyed(key, item, b, level);
+    }
+
+    /// Writes the top-level items of the `source` to the buffer `b` at the indent `level`.
+    ///
+    /// The items are keyed by their `Debug` output since boxed statements are not `Hash`, so the
+    /// `Debug` output of each item must include everything that affects the written code. An item
+    /// with incomplete `Debug` output is written from the cache after a change that is not shown.
+    /// The `Debug` format is not stable between compiler versions, so the cache is only reused by
+    /// builds with the same compiler.
+    pub fn render_source(&mut self, source: &Source, b: &mut CodeBuffer, level: usize) {
+        for item in source.statements() {
+            let item: &dyn Statement = item.as_ref();
+            let type_name: &str = "dyn Statement";
+            // The `Debug` format is not stable, so the key includes the compiler and crate
+            // versions. (a change of either only invalidates the cache)
+            let key: u128 = Self::key(b, level, type_name, |h| write!(h, "{:?}", item).unwrap());
+            self.render_keyed(key, item, b, level);
+        }
+    }
+
+    /// Computes the key of an item of the `type_name` hashed by the function `f`.
+    fn key<F>(b: &CodeBuffer, level: usize, type_name: &str, f: F) -> u128
+    where
+        F: FnOnce(&mut StableHasher128),
+    {
+        let mut hasher: StableHasher128 = StableHasher128::default();
+        env!("CARGO_PKG_VERSION").hash(&mut hasher);
+        env!("CODE_GEN_RUSTC_VERSION").hash(&mut hasher);
+        b.hash_config(&mut hasher);
+        level.hash(&mut hasher);
+        type_name.hash(&mut hasher);
+        f(&mut hasher);
+        hasher.finish_128()
+    }
+
+    /// Writes the `item` with the `key` to the buffer `b` at the indent `level`.
+    fn render_keyed(&mut self, key: u128, item: &dyn Statement, b: &mut CodeBuffer, level: usize) {
+        if b.source_map().is_some() {
+            item.write(b, level);
+            return;
+        }
+        self.used.insert(key);
+        if let Some(code) = self.entries.get(&key) {
+            self.hits += 1;
+            b.write(code.as_str());
+        } else {
+            self.misses += 1;
+            let mut fork: CodeBuffer = b.plain_fork();
+            item.write(&mut fork, level);
+            let code: String = fork.into();
+            b.write(code.as_str());
+            self.entries.insert(key, code);
+        }
+    }
+}
@@ -0,0 +1,15 @@
+/// The action taken for a file when writing an output tree.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum WriteAction {
+    /// The file did not exist and was created.
+    Created,
+
+    /// The file content changed and was rewritten.
+    Updated,
+
+    /// The file content did not change and the file was not touched.
+    Unchanged,
+
+    /// The file was generated previously but is no longer in the tree and was deleted.
+    Deleted,
+}
@@ -2,6 +2,7 @@ use crate::{CodeBuffer, Expression};
 
 /// An access level.
 #[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Access {
     #[default]
     Private,
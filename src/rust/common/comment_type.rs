@@ -1,4 +1,4 @@
-use crate::CodeBuffer;
+use crate::{CodeBuffer, CommentSyntax};
 
 /// A comment type.
 #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
@@ -8,11 +8,24 @@ pub enum CommentType {
     OuterLineDoc,
 }
 
+impl CommentType {
+    //! Syntax
+
+    /// Gets the comment syntax. (for writing wrapped text with a `Comment`)
+    pub const fn syntax(&self) -> CommentSyntax {
+        match self {
+            Self::Line => CommentSyntax::C_LIKE,
+            Self::InnerLineDoc => CommentSyntax::RUST_INNER_DOC,
+            Self::OuterLineDoc => CommentSyntax::RUST_OUTER_DOC,
+        }
+    }
+}
+
 impl CommentType {
     //! Write
 
     /// Gets the delimiter.
-    const fn delimiter(&self) -> &str {
+    pub const fn delimiter(&self) -> &str {
         match self {
             Self::Line => "//",
             Self::InnerLineDoc => "//!",
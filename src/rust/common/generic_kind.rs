@@ -0,0 +1,23 @@
+use crate::rust::RustType;
+
+/// The kind of a generic parameter.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GenericKind {
+    /// A lifetime parameter. (ex: `'a`)
+    Lifetime,
+
+    /// A type parameter with its optional default type. (ex: `T` or `T = String`)
+    Type {
+        #[cfg_attr(feature = "serde", serde(default))]
+        default: Option<RustType>,
+    },
+
+    /// A const parameter with its type and optional default expression code.
+    /// (ex: `const N: usize` or `const N: usize = { M + 1 }`)
+    Const {
+        rust_type: RustType,
+        #[cfg_attr(feature = "serde", serde(default))]
+        default: Option<String>,
+    },
+}
@@ -0,0 +1,310 @@
+use crate::rust::{GenericKind, Ident, RustType};
+use crate::{CodeBuffer, Doc, Expression, Node, NodeMut, WithName};
+
+/// A generic parameter.
+///
+/// A parameter is a lifetime when its name starts with `'` and a type parameter otherwise, const
+/// parameters are created with `GenericParam::constant`. Type parameters default to a type and
+/// const parameters default to a const expression. (ex: `'a: 'b`, `T: Clone + Send = String` or
+/// `const N: usize = 8`)
+///
+/// Defaults are only allowed on the generics of type declarations and traits. Impl blocks and
+/// functions write their generics without the defaults.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenericParam {
+    name: Ident,
+    kind: GenericKind,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bounds: Vec<RustType>,
+}
+
+impl From<&str> for GenericParam {
+    fn from(name: &str) -> Self {
+        Self::from(name.to_string())
+    }
+}
+
+impl From<String> for GenericParam {
+    fn from(name: String) -> Self {
+        let kind: GenericKind = if name.starts_with('\'') {
+            GenericKind::Lifetime
+        } else {
+            GenericKind::Type { default: None }
+        };
+        Self {
+            name: name.into(),
+            kind,
+            bounds: Vec::default(),
+        }
+    }
+}
+
+impl<S: Into<String>, T: Into<RustType>> From<(S, T)> for GenericParam {
+    fn from(tuple: (S, T)) -> Self {
+        Self::from(tuple.0.into()).with_bound(tuple.1)
+    }
+}
+
+impl GenericParam {
+    //! Construction
+
+    /// Creates the const parameter with the `rust_type`. (ex: `const N: usize`)
+    pub fn constant<S, T>(name: S, rust_type: T) -> Self
+    where
+        S: Into<Ident>,
+        T: Into<RustType>,
+    {
+        Self {
+            name: name.into(),
+            kind: GenericKind::Const {
+                rust_type: rust_type.into(),
+                default: None,
+            },
+            bounds: Vec::default(),
+        }
+    }
+}
+
+impl WithName for GenericParam {
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+}
+
+impl GenericParam {
+    //! Kind
+
+    /// Gets the kind.
+    pub fn kind(&self) -> &GenericKind {
+        &self.kind
+    }
+
+    /// Checks if the parameter is a lifetime.
+    pub fn is_lifetime(&self) -> bool {
+        self.kind == GenericKind::Lifetime
+    }
+
+    /// Checks if the parameter is a const parameter.
+    pub fn is_const(&self) -> bool {
+        matches!(self.kind, GenericKind::Const { .. })
+    }
+}
+
+impl GenericParam {
+    //! Bounds
+
+    /// Gets the bounds. (lifetimes are bounded by lifetimes, ex: `'b`)
+    pub fn bounds(&self) -> &[RustType] {
+        self.bounds.as_slice()
+    }
+
+    /// Adds the `bound`.
+    pub fn add_bound<T>(&mut self, bound: T)
+    where
+        T: Into<RustType>,
+    {
+        self.bounds.push(bound.into());
+    }
+
+    /// Adds the `bound`.
+    pub fn with_bound<T>(mut self, bound: T) -> Self
+    where
+        T: Into<RustType>,
+    {
+        self.add_bound(bound);
+        self
+    }
+}
+
+impl GenericParam {
+    //! Default
+
+    /// Checks if the parameter has a default.
+    pub fn has_default(&self) -> bool {
+        match &self.kind {
+            GenericKind::Lifetime => false,
+            GenericKind::Type { default } => default.is_some(),
+            GenericKind::Const { default, .. } => default.is_some(),
+        }
+    }
+
+    /// Gets the optional default type. (`None` for lifetime and const parameters)
+    pub fn default(&self) -> Option<&RustType> {
+        match &self.kind {
+            GenericKind::Type { default } => default.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Sets the `default` type. (only type parameters have a default type)
+    pub fn set_default<T>(&mut self, default: T)
+    where
+        T: Into<RustType>,
+    {
+        if let GenericKind::Type { default: d } = &mut self.kind {
+            *d = Some(default.into());
+        }
+    }
+
+    /// Sets the `default` type. (only type parameters have a default type)
+    pub fn with_default<T>(mut self, default: T) -> Self
+    where
+        T: Into<RustType>,
+    {
+        self.set_default(default);
+        self
+    }
+
+    /// Gets the optional default const expression code. (`None` for lifetime and type parameters)
+    pub fn const_default(&self) -> Option<&str> {
+        match &self.kind {
+            GenericKind::Const { default, .. } => default.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Sets the `default` const expression code. (ex: `8` or `{ M + 1 }`, only const parameters
+    /// have a default const expression)
+    pub fn set_const_default<S>(&mut self, default: S)
+    where
+        S: Into<String>,
+    {
+        if let GenericKind::Const { default: d, .. } = &mut self.kind {
+            *d = Some(default.into());
+        }
+    }
+
+    /// Sets the `default` const expression code. (ex: `8` or `{ M + 1 }`, only const parameters
+    /// have a default const expression)
+    pub fn with_const_default<S>(mut self, default: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.set_const_default(default);
+        self
+    }
+
+    /// Removes the default. (impl blocks and functions cannot declare defaults)
+    pub fn without_default(mut self) -> Self {
+        match &mut self.kind {
+            GenericKind::Lifetime => {}
+            GenericKind::Type { default } => *default = None,
+            GenericKind::Const { default, .. } => *default = None,
+        }
+        self
+    }
+}
+
+impl GenericParam {
+    //! Documents
+
+    /// Gets the declaration document without the bounds. (ex: `T = String` or `const N: usize`)
+    pub fn declaration_doc(&self) -> Doc {
+        self.doc(false, true)
+    }
+
+    /// Gets the impl block document with the bounds and without the default. (ex: `T: Clone`)
+    pub fn impl_doc(&self) -> Doc {
+        self.doc(true, false)
+    }
+
+    /// Gets the function document without the bounds and the default. (ex: `T` or `const N: usize`)
+    pub fn fn_doc(&self) -> Doc {
+        self.doc(false, false)
+    }
+
+    /// Gets the bound document for a where clause. (ex: `T: Clone + Send`, `None` without bounds)
+    pub fn bound_doc(&self) -> Option<Doc> {
+        if self.bounds.is_empty() || self.is_const() {
+            None
+        } else {
+            Some(Doc::concat([Doc::from(self.name()), self.bounds_doc()]))
+        }
+    }
+
+    /// Gets the document of the bounds. (ex: `: Clone + Send`)
+    fn bounds_doc(&self) -> Doc {
+        let mut docs: Vec<Doc> = Vec::with_capacity(self.bounds.len() * 2);
+        for (i, bound) in self.bounds.iter().enumerate() {
+            docs.push(Doc::from(if i == 0 { ": " } else { " + " }));
+            docs.push(bound.to_doc());
+        }
+        Doc::concat(docs)
+    }
+
+    /// Gets the document with or without the bounds and the default.
+    fn doc(&self, with_bounds: bool, with_default: bool) -> Doc {
+        let mut docs: Vec<Doc> = Vec::with_capacity(5);
+        match &self.kind {
+            GenericKind::Lifetime | GenericKind::Type { .. } => {
+                docs.push(Doc::from(self.name()));
+                if with_bounds {
+                    docs.push(self.bounds_doc());
+                }
+            }
+            GenericKind::Const { rust_type, .. } => {
+                docs.push(Doc::from("const "));
+                docs.push(Doc::from(self.name()));
+                docs.push(Doc::from(": "));
+                docs.push(rust_type.to_doc());
+            }
+        }
+        if with_default {
+            match &self.kind {
+                GenericKind::Lifetime => {}
+                GenericKind::Type { default } => {
+                    if let Some(default) = default {
+                        docs.push(Doc::from(" = "));
+                        docs.push(default.to_doc());
+                    }
+                }
+                GenericKind::Const { default, .. } => {
+                    if let Some(default) = default {
+                        docs.push(Doc::from(" = "));
+                        docs.push(Doc::from(default.as_str()));
+                    }
+                }
+            }
+        }
+        Doc::concat(docs)
+    }
+}
+
+impl Expression for GenericParam {
+    fn write(&self, b: &mut CodeBuffer) {
+        b.write_doc(&self.to_doc());
+    }
+
+    fn to_doc(&self) -> Doc {
+        self.doc(true, true)
+    }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        let mut children: Vec<Node> = Vec::default();
+        let mut default: Option<Node> = None;
+        match &self.kind {
+            GenericKind::Lifetime => {}
+            GenericKind::Type { default: d } => default = d.as_ref().map(|c| Node::Expression(c)),
+            GenericKind::Const { rust_type, .. } => children.push(Node::Expression(rust_type)),
+        }
+        children.extend(self.bounds.iter().map(|c| Node::Expression(c)));
+        children.extend(default);
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        let mut children: Vec<NodeMut> = Vec::default();
+        let mut default: Option<NodeMut> = None;
+        match &mut self.kind {
+            GenericKind::Lifetime => {}
+            GenericKind::Type { default: d } => {
+                default = d.as_mut().map(|c| NodeMut::Expression(c))
+            }
+            GenericKind::Const { rust_type, .. } => children.push(NodeMut::Expression(rust_type)),
+        }
+        children.extend(self.bounds.iter_mut().map(|c| NodeMut::Expression(c)));
+        children.extend(default);
+        children
+    }
+}
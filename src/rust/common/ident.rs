@@ -0,0 +1,179 @@
+use crate::rust::RustLanguage;
+use crate::Language;
+use std::fmt::{Display, Formatter};
+
+/// A Rust identifier.
+///
+/// An identifier is created with `Language::escape_identifier`: invalid chars are replaced with
+/// `_`, a leading digit is prefixed with `_` and a keyword is escaped. (ex: `http-status` is
+/// `http_status`, `2xx` is `_2xx`, `type` is `r#type` and `self` is `self_`)
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Ident {
+    name: String,
+}
+
+impl<S: Into<String>> From<S> for Ident {
+    fn from(name: S) -> Self {
+        let name: String = name.into();
+        let name: &str = name.strip_prefix("r#").unwrap_or(name.as_str());
+        Self {
+            name: RustLanguage::default().escape_identifier(name),
+        }
+    }
+}
+
+impl Ident {
+    //! Properties
+
+    /// Gets the identifier as a string. (escaped, ex: `r#type`)
+    pub fn as_str(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Gets the identifier without the raw prefix. (ex: `type` for `r#type`)
+    pub fn unraw(&self) -> &str {
+        self.name.strip_prefix("r#").unwrap_or(self.name.as_str())
+    }
+
+    /// Checks if the identifier is a raw identifier. (ex: `r#type`)
+    pub fn is_raw(&self) -> bool {
+        self.name.starts_with("r#")
+    }
+}
+
+impl Ident {
+    //! Case Conversions
+
+    /// Converts the identifier to `snake_case`.
+    pub fn to_snake_case(&self) -> Self {
+        Self::join(self.words().into_iter().map(|w| w.to_lowercase()), "_")
+    }
+
+    /// Converts the identifier to `SCREAMING_SNAKE_CASE`.
+    pub fn to_screaming_case(&self) -> Self {
+        Self::join(self.words().into_iter().map(|w| w.to_uppercase()), "_")
+    }
+
+    /// Converts the identifier to `PascalCase`. (acronyms are capitalized, ex: `HttpStatus`)
+    pub fn to_pascal_case(&self) -> Self {
+        Self::join(self.words().into_iter().map(Self::capitalize), "")
+    }
+
+    /// Converts the identifier to `camelCase`. (acronyms are capitalized, ex: `httpStatus`)
+    pub fn to_camel_case(&self) -> Self {
+        let words = self.words().into_iter().enumerate().map(|(i, w)| {
+            if i == 0 {
+                w.to_lowercase()
+            } else {
+                Self::capitalize(w)
+            }
+        });
+        Self::join(words, "")
+    }
+
+    /// Splits the identifier into words.
+    ///
+    /// Words are separated by non-alphanumeric chars, lowercase-to-uppercase changes and the end
+    /// of acronyms. (ex: `HTTPStatus2xx` is `HTTP`, `Status2xx`) Digits belong to the preceding
+    /// word.
+    pub fn words(&self) -> Vec<&str> {
+        let mut words: Vec<&str> = Vec::default();
+        for part in self.unraw().split(|c: char| !c.is_alphanumeric()) {
+            let chars: Vec<(usize, char)> = part.char_indices().collect();
+            let mut start: usize = 0;
+            for i in 1..chars.len() {
+                let (prev, c) = (chars[i - 1].1, chars[i].1);
+                let next: Option<char> = chars.get(i + 1).map(|(_, c)| *c);
+                let boundary: bool = c.is_uppercase()
+                    && (!prev.is_uppercase() || next.is_some_and(|n| n.is_lowercase()));
+                if boundary {
+                    words.push(&part[start..chars[i].0]);
+                    start = chars[i].0;
+                }
+            }
+            if start < part.len() {
+                words.push(&part[start..]);
+            }
+        }
+        words
+    }
+
+    /// Capitalizes the first char of the `word` and lowercases the rest.
+    fn capitalize(word: &str) -> String {
+        let mut chars = word.chars();
+        match chars.next() {
+            Some(first) => first
+                .to_uppercase()
+                .chain(chars.flat_map(char::to_lowercase))
+                .collect(),
+            None => String::default(),
+        }
+    }
+
+    /// Joins the `words` with the `separator`.
+    fn join<I>(words: I, separator: &str) -> Self
+    where
+        I: Iterator<Item = String>,
+    {
+        Self::from(words.collect::<Vec<String>>().join(separator))
+    }
+}
+
+impl AsRef<str> for Ident {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for Ident {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Ident {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Ident {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(Self::from(String::deserialize(deserializer)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rust::Ident;
+
+    #[test]
+    fn escapes_keywords() {
+        assert_eq!(Ident::from("type").as_str(), "r#type");
+        assert_eq!(Ident::from("r#type").as_str(), "r#type");
+        assert_eq!(Ident::from("self").as_str(), "self_");
+    }
+
+    #[test]
+    fn replaces_invalid_chars() {
+        assert_eq!(Ident::from("http-status").as_str(), "http_status");
+        assert_eq!(Ident::from("a b.c").as_str(), "a_b_c");
+    }
+
+    #[test]
+    fn prefixes_leading_digits() {
+        assert_eq!(Ident::from("2xx").as_str(), "_2xx");
+        assert_eq!(
+            Ident::from("2xx-status").to_pascal_case().as_str(),
+            "_2xxStatus"
+        );
+    }
+}
@@ -1,5 +1,11 @@
 pub use access::*;
 pub use comment_type::*;
+pub use generic_kind::*;
+pub use generic_param::*;
+pub use ident::*;
+pub use rust_language::*;
+pub use validate::*;
+pub use validator::*;
 pub use with_access::*;
 pub use with_attributes::*;
 pub use with_comments::*;
@@ -8,6 +14,12 @@ pub use with_unsafe_flag::*;
 
 mod access;
 mod comment_type;
+mod generic_kind;
+mod generic_param;
+mod ident;
+mod rust_language;
+mod validate;
+mod validator;
 mod with_access;
 mod with_attributes;
 mod with_comments;
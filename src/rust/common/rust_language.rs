@@ -0,0 +1,62 @@
+use crate::{CommentSyntax, Language};
+
+/// The Rust language.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+pub struct RustLanguage {
+    _nothing: (),
+}
+
+impl RustLanguage {
+    //! Constants
+
+    /// The reserved keywords. (strict and reserved keywords of the 2021 edition)
+    pub const KEYWORDS: &'static [&'static str] = &[
+        "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+        "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if",
+        "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv",
+        "pub", "ref", "return", "self", "static", "struct", "super", "trait", "true", "try",
+        "type", "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+    ];
+
+    /// The keywords that cannot be written as raw identifiers.
+    pub const NON_RAW_KEYWORDS: &'static [&'static str] = &["Self", "crate", "self", "super"];
+}
+
+impl Language for RustLanguage {
+    fn name(&self) -> &str {
+        "rust"
+    }
+
+    fn block_open(&self) -> &str {
+        "{"
+    }
+
+    fn block_close(&self) -> Option<&str> {
+        Some("}")
+    }
+
+    fn statement_terminator(&self) -> &str {
+        ";"
+    }
+
+    fn comment_syntax(&self) -> CommentSyntax {
+        CommentSyntax::C_LIKE
+    }
+
+    fn keywords(&self) -> &[&str] {
+        Self::KEYWORDS
+    }
+
+    /// Escapes the `keyword` as a raw identifier. (ex: `r#type`, or `self_` if it cannot be raw)
+    fn escape_keyword(&self, keyword: &str) -> String {
+        if Self::NON_RAW_KEYWORDS.contains(&keyword) {
+            format!("{}_", keyword)
+        } else {
+            format!("r#{}", keyword)
+        }
+    }
+
+    fn string_literal(&self, value: &str) -> String {
+        format!("{:?}", value)
+    }
+}
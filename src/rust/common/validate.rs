@@ -0,0 +1,21 @@
+use crate::rust::Validator;
+use crate::{Error, Source, WithStatements};
+
+/// A node of the Rust model that can be validated before it is written.
+pub trait Validate {
+    /// Validates the node and its children, recording the errors in the validator `v`.
+    fn validate_with(&self, v: &mut Validator);
+
+    /// Validates the node and its children.
+    fn validate(&self) -> Result<(), Error> {
+        let mut v: Validator = Validator::default();
+        self.validate_with(&mut v);
+        v.into_result()
+    }
+}
+
+impl Validate for Source {
+    fn validate_with(&self, v: &mut Validator) {
+        v.validate_statements(self.statements());
+    }
+}
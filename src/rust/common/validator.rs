@@ -0,0 +1,248 @@
+use crate::rust::{
+    ConstInit, Enum, Function, GenericParam, ImplBlock, RustLanguage, RustType, StaticInit, Struct,
+    ThreadLocal, Trait, TypeDec, Union, Validate,
+};
+use crate::{Error, Language, Node, Statement, ValidationError, ValidationErrorKind, WithName};
+use std::collections::HashSet;
+
+/// Collects the validation errors of the nodes of a Rust model.
+///
+/// The validator tracks the path of the node being validated so each error points at its node.
+#[derive(Clone, Debug, Default)]
+pub struct Validator {
+    path: Vec<(&'static str, String)>,
+    errors: Vec<ValidationError>,
+}
+
+impl Validator {
+    //! Errors
+
+    /// Gets the recorded errors.
+    pub fn errors(&self) -> &[ValidationError] {
+        self.errors.as_slice()
+    }
+
+    /// Records an error of the `kind` for the current node.
+    pub fn error(&mut self, kind: ValidationErrorKind) {
+        self.errors
+            .push(ValidationError::new(self.path.clone(), kind));
+    }
+
+    /// Converts the validator into the result of the validation.
+    pub fn into_result(self) -> Result<(), Error> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Validation(self.errors))
+        }
+    }
+}
+
+impl Validator {
+    //! Nodes
+
+    /// Validates the child node of the `kind` with the `name` with the function `f`.
+    pub fn node<F>(&mut self, kind: &'static str, name: &str, f: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        self.path.push((kind, name.to_string()));
+        f(self);
+        self.path.pop();
+    }
+
+    /// Validates the `statements`. (Rust declarations are validated, other statements skipped)
+    pub fn validate_statements(&mut self, statements: &[Box<dyn Statement>]) {
+        for statement in statements {
+            let statement: &dyn Statement = statement.as_ref();
+            if let Some(s) = statement.downcast_ref::<Struct>() {
+                s.validate_with(self);
+            } else if let Some(s) = statement.downcast_ref::<Union>() {
+                s.validate_with(self);
+            } else if let Some(s) = statement.downcast_ref::<Enum>() {
+                s.validate_with(self);
+            } else if let Some(s) = statement.downcast_ref::<Trait>() {
+                s.validate_with(self);
+            } else if let Some(s) = statement.downcast_ref::<ImplBlock>() {
+                s.validate_with(self);
+            } else if let Some(s) = statement.downcast_ref::<Function>() {
+                s.validate_with(self);
+            } else if let Some(s) = statement.downcast_ref::<TypeDec>() {
+                s.validate_with(self);
+            } else if let Some(s) = statement.downcast_ref::<ConstInit>() {
+                s.validate_with(self);
+            } else if let Some(s) = statement.downcast_ref::<StaticInit>() {
+                s.validate_with(self);
+            } else if let Some(s) = statement.downcast_ref::<ThreadLocal>() {
+                s.validate_with(self);
+            } else {
+                self.validate_statements_of(statement);
+            }
+        }
+    }
+
+    /// Validates the statements nested in the `statement`.
+    fn validate_statements_of(&mut self, statement: &dyn Statement) {
+        for child in statement.children() {
+            match child {
+                Node::Statement(statement) => self.validate_statements_of(statement),
+                Node::Statements(statements) => self.validate_statements(statements),
+                Node::Expression(_) | Node::Comments(_) => {}
+            }
+        }
+    }
+}
+
+impl Validator {
+    //! Checks
+
+    /// Checks that the `name` is a valid identifier. (raw identifiers are allowed, ex: `r#type`)
+    ///
+    /// The `_` wildcard is not a valid name.
+    pub fn check_identifier(&mut self, name: &str) {
+        let language: RustLanguage = RustLanguage::default();
+        let valid: bool = match name.strip_prefix("r#") {
+            Some(raw) => {
+                (language.is_identifier(raw) || language.is_keyword(raw))
+                    && !RustLanguage::NON_RAW_KEYWORDS.contains(&raw)
+                    && raw != "_"
+            }
+            None => language.is_identifier(name) && name != "_",
+        };
+        if !valid {
+            self.error(ValidationErrorKind::InvalidIdentifier {
+                name: name.to_string(),
+            });
+        }
+    }
+
+    /// Checks that the `names` of the child nodes of the `kind` are unique.
+    pub fn check_unique<'a, I>(&mut self, kind: &'static str, names: I)
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut seen: HashSet<&str> = HashSet::default();
+        for name in names {
+            if !seen.insert(name) {
+                self.node(kind, name, |v| {
+                    v.error(ValidationErrorKind::Duplicate {
+                        kind,
+                        name: name.to_string(),
+                    })
+                });
+            }
+        }
+    }
+
+    /// Checks the `generics` are valid, ordered, unique and used by the `types`.
+    ///
+    /// Only the `types` of the item count as uses, a generic mentioned only by the bounds or
+    /// defaults of the other generics is unused. (ex: `T` of `struct Foo<T, U: Into<T>>(U);`)
+    /// Lifetimes must be declared before type and const parameters, and parameters with defaults
+    /// must be declared after the parameters without defaults.
+    pub fn check_generics<'a, I>(&mut self, generics: &[GenericParam], types: I)
+    where
+        I: IntoIterator<Item = &'a RustType>,
+    {
+        let types: Vec<&RustType> = types.into_iter().collect();
+        for (i, generic) in generics.iter().enumerate() {
+            self.node("generic", generic.name(), |v| {
+                v.check_identifier(generic.name().trim_start_matches('\''));
+                let previous: &[GenericParam] = &generics[..i];
+                if generic.is_lifetime() && previous.iter().any(|g| !g.is_lifetime()) {
+                    v.error(ValidationErrorKind::MisplacedLifetime {
+                        name: generic.name().to_string(),
+                    });
+                }
+                if !generic.is_lifetime()
+                    && !generic.has_default()
+                    && previous.iter().any(GenericParam::has_default)
+                {
+                    v.error(ValidationErrorKind::NonTrailingDefault {
+                        name: generic.name().to_string(),
+                    });
+                }
+                if generic.is_const() && !generic.bounds().is_empty() {
+                    v.error(ValidationErrorKind::BoundedConst {
+                        name: generic.name().to_string(),
+                    });
+                }
+                let used: bool = types.iter().any(|t| Self::mentions(t, generic.name()));
+                if !used {
+                    v.error(ValidationErrorKind::UnusedGeneric {
+                        name: generic.name().to_string(),
+                    });
+                }
+            });
+        }
+        self.check_unique("generic", generics.iter().map(|g| g.name()));
+    }
+
+    /// Checks that the `generics` have no defaults. (for impl blocks and functions)
+    pub fn check_no_generic_defaults(&mut self, generics: &[GenericParam]) {
+        for generic in generics.iter().filter(|g| g.has_default()) {
+            self.node("generic", generic.name(), |v| {
+                v.error(ValidationErrorKind::UnexpectedDefault {
+                    name: generic.name().to_string(),
+                });
+            });
+        }
+    }
+
+    /// Checks if the `rust_type` mentions the `name`. (types are split into identifiers and
+    /// lifetimes)
+    fn mentions(rust_type: &RustType, name: &str) -> bool {
+        rust_type
+            .to_string()
+            .split(|c: char| !c.is_alphanumeric() && c != '_' && c != '\'')
+            .any(|part| part == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rust::{GenericParam, Struct, StructKind, Validate, WithGenerics};
+    use crate::{Error, ValidationErrorKind};
+
+    fn error_kinds<V: Validate>(node: &V) -> Vec<ValidationErrorKind> {
+        match node.validate() {
+            Ok(()) => Vec::default(),
+            Err(Error::Validation(errors)) => errors.iter().map(|e| e.kind().clone()).collect(),
+            Err(error) => panic!("unexpected error: {}", error),
+        }
+    }
+
+    #[test]
+    fn generic_used_only_by_bounds_is_unused() {
+        let s: Struct = Struct::from("Foo")
+            .with_kind(StructKind::Tuple)
+            .with_generic("T")
+            .with_generic(("U", "Into<T>"))
+            .with_tuple_field("U");
+        let expected: ValidationErrorKind = ValidationErrorKind::UnusedGeneric {
+            name: "T".to_string(),
+        };
+        assert_eq!(error_kinds(&s), vec![expected]);
+    }
+
+    #[test]
+    fn const_generic_with_bounds() {
+        let s: Struct = Struct::from("Foo")
+            .with_kind(StructKind::Tuple)
+            .with_generic(GenericParam::constant("N", "usize").with_bound("Copy"))
+            .with_tuple_field("[u8; N]");
+        let expected: ValidationErrorKind = ValidationErrorKind::BoundedConst {
+            name: "N".to_string(),
+        };
+        assert_eq!(error_kinds(&s), vec![expected]);
+    }
+
+    #[test]
+    fn wildcard_is_not_an_identifier() {
+        let expected: ValidationErrorKind = ValidationErrorKind::InvalidIdentifier {
+            name: "_".to_string(),
+        };
+        assert_eq!(error_kinds(&Struct::from("_")), vec![expected]);
+        assert!(error_kinds(&Struct::from("_a")).is_empty());
+    }
+}
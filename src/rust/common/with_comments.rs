@@ -1,5 +1,5 @@
 use crate::rust::CommentType;
-use crate::CodeBuffer;
+use crate::{CodeBuffer, Comment, CommentStyle, Statement};
 
 /// An element with comment lines.
 pub trait WithComments: Sized {
@@ -20,10 +20,58 @@ pub trait WithComments: Sized {
     where
         S: Into<String>;
 
-    /// Writes the comment lines.
+    /// Writes the comment lines. (each line is written as is, see `write_wrapped_comments`)
     fn write_comments(&self, comment_type: CommentType, b: &mut CodeBuffer, level: usize) {
         for line in self.comments() {
             comment_type.write_line(b, level, line.as_str());
         }
     }
+
+    /// Writes the comment lines wrapping the lines over the max width of the code buffer.
+    ///
+    /// Each line over the width is wrapped on its own as a `Comment` with the syntax of the
+    /// `comment_type`, other lines are written as is. (a single leading space of a wrapped line is
+    /// replaced by the space after the delimiter)
+    fn write_wrapped_comments(&self, comment_type: CommentType, b: &mut CodeBuffer, level: usize) {
+        for line in self.comments() {
+            let width: usize = b.indent_width(level)
+                + comment_type.delimiter().chars().count()
+                + line.chars().count();
+            match b.max_width() {
+                Some(max_width) if width > max_width => {
+                    Comment::from(line.strip_prefix(' ').unwrap_or(line.as_str()))
+                        .with_syntax(comment_type.syntax())
+                        .with_style(CommentStyle::Line)
+                        .write(b, level);
+                }
+                _ => comment_type.write_line(b, level, line.as_str()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rust::{CommentType, Struct, WithComments};
+    use crate::CodeBuffer;
+
+    #[test]
+    fn writes_lines_as_is() {
+        let s: Struct = Struct::from("S")
+            .with_comment("foo")
+            .with_comment(" | a | b |");
+        let mut b: CodeBuffer = CodeBuffer::default().with_max_width(10);
+        s.write_comments(CommentType::OuterLineDoc, &mut b, 0);
+        assert_eq!(String::from(b), "///foo\n/// | a | b |\n");
+    }
+
+    #[test]
+    fn wraps_long_lines() {
+        let s: Struct = Struct::from("S")
+            .with_comment(" one two three four")
+            .with_comment(" five");
+        let mut b: CodeBuffer = CodeBuffer::default().with_max_width(14);
+        s.write_wrapped_comments(CommentType::OuterLineDoc, &mut b, 0);
+        assert_eq!(String::from(b), "/// one two\n/// three four\n/// five\n");
+    }
 }
@@ -1,35 +1,88 @@
-use crate::rust::Var;
-use crate::{CodeBuffer, Expression};
+use crate::rust::GenericParam;
+use crate::{CodeBuffer, Doc, Expression, WithName};
 
-/// An element with generic type parameters.
+/// An element with generic parameters.
 pub trait WithGenerics: Sized {
-    /// Gets the generic type parameters.
-    fn generics(&self) -> &[Var];
+    /// Gets the generic parameters.
+    fn generics(&self) -> &[GenericParam];
 
-    /// Adds the `generic` type parameter.
-    fn add_generic<V>(&mut self, generic: V)
+    /// Adds the `generic` parameter.
+    fn add_generic<G>(&mut self, generic: G)
     where
-        V: Into<Var>;
+        G: Into<GenericParam>;
 
-    /// Adds the `generic` type parameter.
-    fn with_generic<V>(mut self, generic: V) -> Self
+    /// Adds the `generic` parameter.
+    fn with_generic<G>(mut self, generic: G) -> Self
     where
-        V: Into<Var>,
+        G: Into<GenericParam>,
     {
         self.add_generic(generic);
         self
     }
 
-    /// Writes the generic brackets. (ex: `<A: TypeA, B: TypeB>`)
+    /// Gets the generic brackets document with the bounds and the defaults.
+    /// (ex: `<'a, T: Clone + Send = String, const N: usize>`, empty without generics)
+    fn generic_brackets_doc(&self) -> Doc {
+        if self.generics().is_empty() {
+            Doc::concat([])
+        } else {
+            let generics: Vec<Doc> = self.generics().iter().map(|g| g.to_doc()).collect();
+            Doc::comma_list("<", generics, ">")
+        }
+    }
+
+    /// Writes the generic brackets with the bounds. (ex: `<'a, T: Clone + Send>`)
     fn write_generic_brackets(&self, b: &mut CodeBuffer) {
-        b.write("<");
-        if let Some((first, rest)) = self.generics().split_first() {
-            first.write(b);
-            for generic in rest {
-                b.write(", ");
-                generic.write(b);
-            }
+        b.write_doc(&self.generic_brackets_doc());
+    }
+
+    /// Gets the impl block generic brackets document with the bounds and without the defaults.
+    /// (ex: `<'a, T: Clone + Send, const N: usize>`, empty without generics)
+    fn generic_impl_brackets_doc(&self) -> Doc {
+        if self.generics().is_empty() {
+            Doc::concat([])
+        } else {
+            let generics: Vec<Doc> = self.generics().iter().map(|g| g.impl_doc()).collect();
+            Doc::comma_list("<", generics, ">")
+        }
+    }
+
+    /// Gets the generic brackets document without the bounds.
+    /// (ex: `<'a, T = String, const N: usize>`, empty without generics)
+    fn generic_params_doc(&self) -> Doc {
+        if self.generics().is_empty() {
+            Doc::concat([])
+        } else {
+            let generics: Vec<Doc> = self
+                .generics()
+                .iter()
+                .map(|g| g.declaration_doc())
+                .collect();
+            Doc::comma_list("<", generics, ">")
+        }
+    }
+
+    /// Gets the generic names document. (ex: `<'a, T, N>`, empty without generics)
+    fn generic_names_doc(&self) -> Doc {
+        if self.generics().is_empty() {
+            Doc::concat([])
+        } else {
+            let names: Vec<Doc> = self
+                .generics()
+                .iter()
+                .map(|g| Doc::from(g.name()))
+                .collect();
+            Doc::comma_list("<", names, ">")
+        }
+    }
+
+    /// Gets the generic where clause document. (ex: ` where 'a: 'b, T: Clone + Send`)
+    fn generic_where_doc(&self) -> Doc {
+        let mut docs: Vec<Doc> = Vec::with_capacity(self.generics().len() * 2);
+        for bound in self.generics().iter().filter_map(|g| g.bound_doc()) {
+            docs.push(Doc::from(if docs.is_empty() { " where " } else { ", " }));
+            docs.push(bound);
         }
-        b.write(">");
+        Doc::concat(docs)
     }
 }
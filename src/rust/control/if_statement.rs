@@ -1,9 +1,20 @@
-use crate::{CodeBuffer, Expression, Literal, Source, Statement, WithStatements};
+use crate::{CodeBuffer, Expression, Literal, Node, NodeMut, Source, Statement, WithStatements};
 
 /// An `if` statement with an optional `else` clause.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IfStatement {
+    #[cfg_attr(feature = "serde", serde(with = "crate::rust::data::expression_text"))]
     condition: Box<dyn Expression>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::rust::data::source_statements")
+    )]
     success_statements: Source,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::rust::data::source_statements")
+    )]
     else_statements: Source,
 }
 
@@ -97,4 +108,20 @@ impl Statement for IfStatement {
         }
         b.line(level, "}");
     }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        vec![
+            Node::Expression(self.condition.as_ref()),
+            Node::Statement(&self.success_statements),
+            Node::Statement(&self.else_statements),
+        ]
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        vec![
+            NodeMut::Expression(self.condition.as_mut()),
+            NodeMut::Statement(&mut self.success_statements),
+            NodeMut::Statement(&mut self.else_statements),
+        ]
+    }
 }
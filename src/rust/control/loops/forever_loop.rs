@@ -1,8 +1,13 @@
-use crate::{CodeBuffer, Statement, WithStatements};
+use crate::{CodeBuffer, Node, NodeMut, Statement, WithStatements};
 
 /// A forever `loop` statement.
-#[derive(Default)]
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ForeverLoop {
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::rust::data::statement_list")
+    )]
     statements: Vec<Box<dyn Statement>>,
 }
 
@@ -23,4 +28,12 @@ impl Statement for ForeverLoop {
         self.write_curly_statement_block(b, level);
         b.end_line();
     }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        vec![Node::Statements(&self.statements)]
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        vec![NodeMut::Statements(&mut self.statements)]
+    }
 }
@@ -1,8 +1,15 @@
-use crate::{CodeBuffer, Expression, Literal, Statement, WithStatements};
+use crate::{CodeBuffer, Expression, Literal, Node, NodeMut, Statement, WithStatements};
 
 /// A `match` statement case.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MatchCase {
+    #[cfg_attr(feature = "serde", serde(with = "crate::rust::data::expression_text"))]
     expression: Box<dyn Expression>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::rust::data::statement_list")
+    )]
     statements: Vec<Box<dyn Statement>>,
 }
 
@@ -45,4 +52,18 @@ impl Statement for MatchCase {
         self.write_curly_statement_block(b, level);
         b.end_line();
     }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        vec![
+            Node::Expression(self.expression.as_ref()),
+            Node::Statements(&self.statements),
+        ]
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        vec![
+            NodeMut::Expression(self.expression.as_mut()),
+            NodeMut::Statements(&mut self.statements),
+        ]
+    }
 }
@@ -1,10 +1,15 @@
 use crate::rust::{MatchCase, Var};
-use crate::{CodeBuffer, Expression, Literal, Statement};
+use crate::{CodeBuffer, Expression, Literal, Node, NodeMut, Statement};
 
 /// A `match` statement.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MatchStatement {
+    #[cfg_attr(feature = "serde", serde(default))]
     assignment: Option<Var>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::rust::data::expression_text"))]
     expression: Box<dyn Expression>,
+    #[cfg_attr(feature = "serde", serde(default))]
     match_cases: Vec<MatchCase>,
 }
 
@@ -92,4 +97,20 @@ impl Statement for MatchStatement {
             b.line(level, "}")
         }
     }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        let mut children: Vec<Node> = Vec::default();
+        children.extend(self.assignment.as_ref().map(|c| Node::Expression(c)));
+        children.push(Node::Expression(self.expression.as_ref()));
+        children.extend(self.match_cases.iter().map(|c| Node::Statement(c)));
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        let mut children: Vec<NodeMut> = Vec::default();
+        children.extend(self.assignment.as_mut().map(|c| NodeMut::Expression(c)));
+        children.push(NodeMut::Expression(self.expression.as_mut()));
+        children.extend(self.match_cases.iter_mut().map(|c| NodeMut::Statement(c)));
+        children
+    }
 }
@@ -0,0 +1,83 @@
+use crate::rust::{ForeverLoop, IfStatement, MatchStatement, VarInit, WhileLoop};
+use crate::{EmptyLine, ExpressionStatement, Literal, Semi, Statement, TextBlock};
+use serde::{Deserialize, Serialize};
+
+/// A function-body statement in the data representation of the model.
+///
+/// Body statements are a closed set of the statement types. Expressions are represented by their
+/// code and are loaded as literals.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum BodyStatement {
+    /// A terminated literal statement. (`Semi<Literal>`)
+    Semi(String),
+
+    /// A literal statement. (`ExpressionStatement<Literal>`)
+    Expression(String),
+
+    /// An empty line.
+    EmptyLine,
+
+    /// A block of multi-line text.
+    TextBlock(String),
+
+    /// A variable initialization statement.
+    VarInit(VarInit),
+
+    /// An `if` statement.
+    If(IfStatement),
+
+    /// A `while` loop.
+    While(WhileLoop),
+
+    /// A `loop` loop.
+    Loop(ForeverLoop),
+
+    /// A `match` statement.
+    Match(MatchStatement),
+}
+
+impl BodyStatement {
+    //! Conversions
+
+    /// Converts the `statement` to a body statement. (`None` if the statement type is unsupported)
+    pub fn from_statement(statement: &dyn Statement) -> Option<Self> {
+        if let Some(semi) = statement.downcast_ref::<Semi<Literal>>() {
+            Some(Self::Semi(semi.expression().value().to_string()))
+        } else if let Some(e) = statement.downcast_ref::<ExpressionStatement<Literal>>() {
+            Some(Self::Expression(e.expression().value().to_string()))
+        } else if statement.is::<EmptyLine>() {
+            Some(Self::EmptyLine)
+        } else if let Some(text_block) = statement.downcast_ref::<TextBlock>() {
+            Some(Self::TextBlock(text_block.lines().join("\n")))
+        } else if let Some(var_init) = statement.downcast_ref::<VarInit>() {
+            Some(Self::VarInit(var_init.clone()))
+        } else if let Some(if_statement) = statement.downcast_ref::<IfStatement>() {
+            Some(Self::If(if_statement.clone()))
+        } else if let Some(while_loop) = statement.downcast_ref::<WhileLoop>() {
+            Some(Self::While(while_loop.clone()))
+        } else if let Some(forever_loop) = statement.downcast_ref::<ForeverLoop>() {
+            Some(Self::Loop(forever_loop.clone()))
+        } else {
+            statement
+                .downcast_ref::<MatchStatement>()
+                .map(|match_statement| Self::Match(match_statement.clone()))
+        }
+    }
+
+    /// Converts the body statement to a boxed statement.
+    pub fn into_statement(self) -> Box<dyn Statement> {
+        match self {
+            Self::Semi(literal) => Box::new(Semi::from(literal)),
+            Self::Expression(literal) => {
+                Box::new(ExpressionStatement::from(Literal::from(literal)))
+            }
+            Self::EmptyLine => Box::new(EmptyLine::default()),
+            Self::TextBlock(text) => Box::new(TextBlock::from(text)),
+            Self::VarInit(var_init) => Box::new(var_init),
+            Self::If(if_statement) => Box::new(if_statement),
+            Self::While(while_loop) => Box::new(while_loop),
+            Self::Loop(forever_loop) => Box::new(forever_loop),
+            Self::Match(match_statement) => Box::new(match_statement),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+//! Serializes boxed expressions as their code and deserializes them as literals.
+
+use crate::{CodeBuffer, Expression, Literal};
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Serializes the `expression` as its code.
+pub(crate) fn serialize<E, S>(expression: &E, serializer: S) -> Result<S::Ok, S::Error>
+where
+    E: AsRef<dyn Expression> + ?Sized,
+    S: Serializer,
+{
+    let mut b: CodeBuffer = CodeBuffer::new("", "", 64);
+    expression.as_ref().write(&mut b);
+    serializer.serialize_str(String::from(b).as_str())
+}
+
+/// Deserializes the expression as a literal.
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Box<dyn Expression>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Box::new(Literal::from(String::deserialize(deserializer)?)))
+}
@@ -0,0 +1,7 @@
+pub use body_statement::*;
+
+mod body_statement;
+pub(crate) mod expression_text;
+pub(crate) mod reference_lifetime;
+pub(crate) mod source_statements;
+pub(crate) mod statement_list;
@@ -0,0 +1,40 @@
+//! Serializes reference lifetimes as optional names. (ex: `"a"` or `"static"`)
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The name of the static lifetime.
+const STATIC: &str = "static";
+
+/// Serializes the optional `lifetime` as an optional name.
+pub(crate) fn serialize<S>(
+    lifetime: &Option<Option<char>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let name: Option<String> = lifetime.map(|lifetime| match lifetime {
+        Some(c) => c.to_string(),
+        None => STATIC.to_string(),
+    });
+    name.serialize(serializer)
+}
+
+/// Deserializes the optional lifetime name.
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Option<Option<char>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)?.as_deref() {
+        None => Ok(None),
+        Some(STATIC) => Ok(Some(None)),
+        Some(name) => {
+            let mut chars = name.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) if c.is_ascii_lowercase() => Ok(Some(Some(c))),
+                _ => Err(D::Error::custom(format!("invalid lifetime: {}", name))),
+            }
+        }
+    }
+}
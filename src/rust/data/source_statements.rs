@@ -0,0 +1,26 @@
+//! Serializes the statements of a source as body statements.
+
+use crate::rust::data::statement_list;
+use crate::{Source, Statement, WithStatements};
+use serde::{Deserializer, Serializer};
+
+/// Serializes the statements of the `source` as body statements.
+pub(crate) fn serialize<S>(source: &Source, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    statement_list::serialize(source.statements(), serializer)
+}
+
+/// Deserializes the body statements as a source.
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Source, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let statements: Vec<Box<dyn Statement>> = statement_list::deserialize(deserializer)?;
+    Ok(statements
+        .into_iter()
+        .fold(Source::default(), |source, statement| {
+            source.with_boxed_statement(statement)
+        }))
+}
@@ -0,0 +1,35 @@
+//! Serializes boxed statements as body statements.
+
+use crate::rust::BodyStatement;
+use crate::Statement;
+use serde::ser::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes the `statements` as body statements. (fails for unsupported statement types)
+pub(crate) fn serialize<L, S>(statements: &L, serializer: S) -> Result<S::Ok, S::Error>
+where
+    L: AsRef<[Box<dyn Statement>]> + ?Sized,
+    S: Serializer,
+{
+    let body: Vec<BodyStatement> = statements
+        .as_ref()
+        .iter()
+        .map(|statement| {
+            BodyStatement::from_statement(statement.as_ref()).ok_or_else(|| {
+                S::Error::custom(format!("unsupported body statement: {:?}", statement))
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    body.serialize(serializer)
+}
+
+/// Deserializes the body statements as boxed statements.
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Box<dyn Statement>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Vec::<BodyStatement>::deserialize(deserializer)?
+        .into_iter()
+        .map(BodyStatement::into_statement)
+        .collect())
+}
@@ -1,17 +1,29 @@
 use crate::rust::CommentType::OuterLineDoc;
 use crate::rust::{
-    Access, Signature, WithAccess, WithAttributes, WithComments, WithSignature, WithUnsafeFlag,
+    Access, Signature, Validate, Validator, WithAccess, WithAttributes, WithComments,
+    WithSignature, WithUnsafeFlag,
 };
-use crate::{CodeBuffer, Statement, WithStatements};
+use crate::{CodeBuffer, Node, NodeMut, Statement, ValidationErrorKind, WithName, WithStatements};
 
 /// A function declaration.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Function {
+    #[cfg_attr(feature = "serde", serde(default))]
     comments: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
     attributes: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
     is_async: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
     is_const: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
     access: Access,
     signature: Signature,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::rust::data::statement_list")
+    )]
     statements: Vec<Box<dyn Statement>>,
 }
 
@@ -116,6 +128,7 @@ impl WithStatements for Function {
 
 impl Statement for Function {
     fn write(&self, b: &mut CodeBuffer, level: usize) {
+        b.open_span("fn", self.signature.name());
         self.write_comments(OuterLineDoc, b, level);
         self.write_attributes(b, level);
         b.indent(level);
@@ -132,5 +145,50 @@ impl Statement for Function {
         b.space();
         self.write_curly_statement_block(b, level);
         b.end_line();
+        b.close_span();
+    }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        vec![
+            Node::Comments(&self.comments),
+            Node::Expression(&self.signature),
+            Node::Statements(&self.statements),
+        ]
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        vec![
+            NodeMut::Comments(&mut self.comments),
+            NodeMut::Expression(&mut self.signature),
+            NodeMut::Statements(&mut self.statements),
+        ]
+    }
+}
+
+impl Validate for Function {
+    fn validate_with(&self, v: &mut Validator) {
+        self.signature.validate_with(v);
+        if self.is_const && self.is_async {
+            v.node("fn", self.signature.name(), |v| {
+                v.error(ValidationErrorKind::ConflictingFlags {
+                    first: "const",
+                    second: "async",
+                })
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rust::Function;
+    use crate::{CodeBuffer, PythonLanguage, Statement, WithStatements};
+
+    #[test]
+    fn writes_rust_braces_for_any_language() {
+        let function: Function = Function::from("f").with_literal("a");
+        let mut b: CodeBuffer = CodeBuffer::default().with_language(PythonLanguage::default());
+        function.write(&mut b, 0);
+        assert_eq!(String::from(b), "fn f() {\n    a\n}\n");
     }
 }
@@ -2,6 +2,7 @@ use crate::{CodeBuffer, Expression};
 
 /// A function receiver.
 #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Receiver {
     Borrowed,
     BorrowedMut,
@@ -1,21 +1,27 @@
 use crate::rust::{
-    Receiver, RustType, Var, WithFnGenerics, WithReceiver, WithResult, WithUnsafeFlag,
-    WithVarParams,
+    GenericParam, Ident, Receiver, RustType, Validate, Validator, Var, WithFnGenerics,
+    WithReceiver, WithResult, WithRustType, WithUnsafeFlag, WithVarParams,
 };
-use crate::{CodeBuffer, Expression, WithName};
+use crate::{CodeBuffer, Doc, Expression, Node, NodeMut, WithName};
 
 /// A function signature.
 #[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Signature {
+    #[cfg_attr(feature = "serde", serde(default))]
     is_unsafe: bool,
-    name: String,
-    generics: Vec<Var>,
+    name: Ident,
+    #[cfg_attr(feature = "serde", serde(default))]
+    generics: Vec<GenericParam>,
+    #[cfg_attr(feature = "serde", serde(default))]
     receiver: Option<Receiver>,
+    #[cfg_attr(feature = "serde", serde(default))]
     params: Vec<Var>,
+    #[cfg_attr(feature = "serde", serde(default))]
     result: Option<RustType>,
 }
 
-impl<S: Into<String>> From<S> for Signature {
+impl<S: Into<Ident>> From<S> for Signature {
     fn from(name: S) -> Self {
         Self {
             is_unsafe: false,
@@ -45,13 +51,13 @@ impl WithName for Signature {
 }
 
 impl WithFnGenerics for Signature {
-    fn generics(&self) -> &[Var] {
+    fn generics(&self) -> &[GenericParam] {
         self.generics.as_slice()
     }
 
-    fn add_generic<V>(&mut self, generic: V)
+    fn add_generic<G>(&mut self, generic: G)
     where
-        V: Into<Var>,
+        G: Into<GenericParam>,
     {
         self.generics.push(generic.into());
     }
@@ -95,18 +101,64 @@ impl WithResult for Signature {
 
 impl Expression for Signature {
     fn write(&self, b: &mut CodeBuffer) {
-        self.write_name(b);
-        self.write_generic_brackets(b);
-        b.write("(");
+        b.write_doc(&self.to_doc());
+    }
+
+    fn to_doc(&self) -> Doc {
+        let mut params: Vec<Doc> = Vec::with_capacity(self.params.len() + 1);
         if let Some(receiver) = self.receiver {
-            receiver.write(b);
-            if !self.params.is_empty() {
-                b.write(", ");
-            }
+            params.push(receiver.to_doc());
         }
-        self.write_params(b);
-        b.write(")");
-        self.write_result(b);
-        self.write_generic_where(b);
+        params.extend(self.param_docs());
+        let mut docs: Vec<Doc> = vec![
+            Doc::from(self.name()),
+            self.generic_brackets_doc(),
+            Doc::comma_list("(", params, ")"),
+        ];
+        if let Some(result) = &self.result {
+            docs.push(Doc::from(" -> "));
+            docs.push(result.to_doc());
+        }
+        docs.push(self.generic_where_doc());
+        Doc::concat(docs)
+    }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        let mut children: Vec<Node> = self.generics.iter().map(|c| Node::Expression(c)).collect();
+        children.extend(self.receiver.as_ref().map(|c| Node::Expression(c)));
+        children.extend(self.params.iter().map(|c| Node::Expression(c)));
+        children.extend(self.result.as_ref().map(|c| Node::Expression(c)));
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        let mut children: Vec<NodeMut> = self
+            .generics
+            .iter_mut()
+            .map(|c| NodeMut::Expression(c))
+            .collect();
+        children.extend(self.receiver.as_mut().map(|c| NodeMut::Expression(c)));
+        children.extend(self.params.iter_mut().map(|c| NodeMut::Expression(c)));
+        children.extend(self.result.as_mut().map(|c| NodeMut::Expression(c)));
+        children
+    }
+}
+
+impl Validate for Signature {
+    fn validate_with(&self, v: &mut Validator) {
+        v.node("fn", self.name(), |v| {
+            v.check_identifier(self.name());
+            let types = self.params.iter().map(|p| p.rust_type());
+            let bounds = self.generics.iter().flat_map(|g| g.bounds());
+            v.check_generics(
+                &self.generics,
+                types.chain(self.result.as_ref()).chain(bounds),
+            );
+            v.check_no_generic_defaults(&self.generics);
+            for param in &self.params {
+                v.node("param", param.name(), |v| v.check_identifier(param.name()));
+            }
+            v.check_unique("param", self.params.iter().map(|p| p.name()));
+        });
     }
 }
@@ -1,47 +1,53 @@
-use crate::rust::Var;
-use crate::{CodeBuffer, Expression, WithName};
+use crate::rust::GenericParam;
+use crate::{CodeBuffer, Doc};
 
 /// An element with function generics.
 pub trait WithFnGenerics: Sized {
-    /// Gets the generic type parameters.
-    fn generics(&self) -> &[Var];
+    /// Gets the generic parameters.
+    fn generics(&self) -> &[GenericParam];
 
-    /// Adds the `generic` type parameter.
-    fn add_generic<V>(&mut self, generic: V)
+    /// Adds the `generic` parameter.
+    fn add_generic<G>(&mut self, generic: G)
     where
-        V: Into<Var>;
+        G: Into<GenericParam>;
 
-    /// Adds the `generic` type parameter.
-    fn with_generic<V>(mut self, generic: V) -> Self
+    /// Adds the `generic` parameter.
+    fn with_generic<G>(mut self, generic: G) -> Self
     where
-        V: Into<Var>,
+        G: Into<GenericParam>,
     {
         self.add_generic(generic);
         self
     }
 
+    /// Gets the generic brackets document without the bounds and the defaults.
+    /// (ex: `<'a, T, const N: usize>`)
+    fn generic_brackets_doc(&self) -> Doc {
+        if self.generics().is_empty() {
+            Doc::concat([])
+        } else {
+            let generics: Vec<Doc> = self.generics().iter().map(|g| g.fn_doc()).collect();
+            Doc::comma_list("<", generics, ">")
+        }
+    }
+
     /// Writes the generic brackets.
     fn write_generic_brackets(&self, b: &mut CodeBuffer) {
-        if let Some((first, rest)) = self.generics().split_first() {
-            b.write("<");
-            first.write_name(b);
-            for generic in rest {
-                b.write(", ");
-                generic.write_name(b);
-            }
-            b.write(">");
+        b.write_doc(&self.generic_brackets_doc());
+    }
+
+    /// Gets the generic where clause document. (ex: ` where 'a: 'b, T: Clone + Send`)
+    fn generic_where_doc(&self) -> Doc {
+        let mut docs: Vec<Doc> = Vec::with_capacity(self.generics().len() * 2);
+        for bound in self.generics().iter().filter_map(|g| g.bound_doc()) {
+            docs.push(Doc::from(if docs.is_empty() { " where " } else { ", " }));
+            docs.push(bound);
         }
+        Doc::concat(docs)
     }
 
     //// Writes the generic where clause.
     fn write_generic_where(&self, b: &mut CodeBuffer) {
-        if let Some((first, rest)) = self.generics().split_first() {
-            b.write(" where ");
-            first.write(b);
-            for generic in rest {
-                b.write(", ");
-                generic.write(b);
-            }
-        }
+        b.write_doc(&self.generic_where_doc());
     }
 }
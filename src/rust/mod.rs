@@ -1,11 +1,15 @@
 pub use common::*;
 pub use control::*;
+#[cfg(feature = "serde")]
+pub use data::*;
 pub use function::*;
 pub use types::*;
 pub use var::*;
 
 mod common;
 mod control;
+#[cfg(feature = "serde")]
+mod data;
 mod function;
 mod types;
 mod var;
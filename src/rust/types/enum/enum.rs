@@ -1,23 +1,41 @@
 use crate::rust::CommentType::OuterLineDoc;
-use crate::rust::{Access, EnumCase, WithAccess, WithComments, WithDerives};
-use crate::{CodeBuffer, EmptyLine, Statement, WithName};
+use crate::rust::{
+    Access, EnumCase, GenericParam, Ident, RustType, Validate, Validator, WithAccess,
+    WithAttributes, WithComments, WithDerives, WithGenerics,
+};
+use crate::{CodeBuffer, EmptyLine, Node, NodeMut, Statement, WithName};
 
 /// An enum declaration.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Enum {
+    #[cfg_attr(feature = "serde", serde(default))]
     comments: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
     derives: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    repr: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    attributes: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
     access: Access,
-    name: String,
+    name: Ident,
+    #[cfg_attr(feature = "serde", serde(default))]
+    generics: Vec<GenericParam>,
+    #[cfg_attr(feature = "serde", serde(default))]
     cases: Vec<EnumCase>,
 }
 
-impl<S: Into<String>> From<S> for Enum {
+impl<S: Into<Ident>> From<S> for Enum {
     fn from(name: S) -> Self {
         Self {
             comments: Vec::default(),
             derives: Vec::default(),
+            repr: None,
+            attributes: Vec::default(),
             access: Access::default(),
             name: name.into(),
+            generics: Vec::default(),
             cases: Vec::default(),
         }
     }
@@ -49,6 +67,56 @@ impl WithDerives for Enum {
     }
 }
 
+impl Enum {
+    //! Repr
+
+    /// Gets the optional representation. (ex: `u8` for `#[repr(u8)]`)
+    pub fn repr(&self) -> Option<&str> {
+        self.repr.as_deref()
+    }
+
+    /// Sets the `repr`esentation. (ex: `u8` for `#[repr(u8)]`)
+    pub fn set_repr<S>(&mut self, repr: S)
+    where
+        S: Into<String>,
+    {
+        self.repr = Some(repr.into());
+    }
+
+    /// Sets the `repr`esentation. (ex: `u8` for `#[repr(u8)]`)
+    pub fn with_repr<S>(mut self, repr: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.set_repr(repr);
+        self
+    }
+
+    /// Writes the representation attribute.
+    fn write_repr(&self, b: &mut CodeBuffer, level: usize) {
+        if let Some(repr) = &self.repr {
+            b.indent(level);
+            b.write("#[repr(");
+            b.write(repr.as_str());
+            b.write(")]");
+            b.end_line();
+        }
+    }
+}
+
+impl WithAttributes for Enum {
+    fn attributes(&self) -> &[String] {
+        self.attributes.as_slice()
+    }
+
+    fn add_attribute<S>(&mut self, attribute: S)
+    where
+        S: Into<String>,
+    {
+        self.attributes.push(attribute.into());
+    }
+}
+
 impl WithAccess for Enum {
     fn access(&self) -> &Access {
         &self.access
@@ -68,6 +136,19 @@ impl WithName for Enum {
     }
 }
 
+impl WithGenerics for Enum {
+    fn generics(&self) -> &[GenericParam] {
+        self.generics.as_slice()
+    }
+
+    fn add_generic<G>(&mut self, generic: G)
+    where
+        G: Into<GenericParam>,
+    {
+        self.generics.push(generic.into());
+    }
+}
+
 impl Enum {
     //! Cases
 
@@ -107,12 +188,16 @@ impl Enum {
 
 impl Statement for Enum {
     fn write(&self, b: &mut CodeBuffer, level: usize) {
+        b.open_span("enum", self.name());
         self.write_comments(OuterLineDoc, b, level);
-        b.indent(level);
         self.write_derives(b, level);
+        self.write_repr(b, level);
+        self.write_attributes(b, level);
+        b.indent(level);
         self.write_access(b);
         b.write("enum ");
         self.write_name(b);
+        self.write_generic_brackets(b);
         b.write(" {");
         if self.cases.is_empty() {
             b.write("}");
@@ -122,5 +207,49 @@ impl Statement for Enum {
             self.write_cases(b, level + 1);
             b.line(level, "}");
         }
+        b.close_span();
+    }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        let mut children: Vec<Node> = vec![Node::Comments(&self.comments)];
+        children.extend(self.generics.iter().map(|c| Node::Expression(c)));
+        children.extend(self.cases.iter().map(|c| Node::Statement(c)));
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        let mut children: Vec<NodeMut> = vec![NodeMut::Comments(&mut self.comments)];
+        children.extend(self.generics.iter_mut().map(|c| NodeMut::Expression(c)));
+        children.extend(self.cases.iter_mut().map(|c| NodeMut::Statement(c)));
+        children
+    }
+}
+
+impl Validate for Enum {
+    fn validate_with(&self, v: &mut Validator) {
+        v.node("enum", self.name(), |v| {
+            v.check_identifier(self.name());
+            let types: Vec<&RustType> =
+                self.cases.iter().flat_map(|c| c.fields().types()).collect();
+            v.check_generics(&self.generics, types);
+            self.cases.iter().for_each(|c| c.validate_with(v));
+            v.check_unique("variant", self.cases.iter().map(|c| c.name()));
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rust::{Enum, EnumCase, WithGenerics};
+    use crate::{CodeBuffer, Statement};
+
+    #[test]
+    fn writes_inline_bounds() {
+        let e: Enum = Enum::from("E")
+            .with_generic(("T", "Clone"))
+            .with_case(EnumCase::from("A"));
+        let mut b: CodeBuffer = CodeBuffer::default();
+        e.write(&mut b, 0);
+        assert_eq!(String::from(b), "enum E<T: Clone> {\n    A,\n}\n");
     }
 }
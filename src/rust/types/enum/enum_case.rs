@@ -1,19 +1,31 @@
-use crate::rust::{CommentType, EnumFields, WithComments};
-use crate::{CodeBuffer, Expression, Statement, WithName};
+use crate::rust::{
+    CommentType, EnumFields, Ident, Validate, Validator, WithAttributes, WithComments,
+};
+use crate::{CodeBuffer, Expression, Node, NodeMut, Statement, WithName};
 
 /// An enum case.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumCase {
+    #[cfg_attr(feature = "serde", serde(default))]
     comments: Vec<String>,
-    name: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    attributes: Vec<String>,
+    name: Ident,
+    #[cfg_attr(feature = "serde", serde(default))]
     fields: EnumFields,
+    #[cfg_attr(feature = "serde", serde(default))]
+    discriminant: Option<String>,
 }
 
-impl<S: Into<String>> From<S> for EnumCase {
+impl<S: Into<Ident>> From<S> for EnumCase {
     fn from(name: S) -> Self {
         Self {
             comments: Vec::default(),
+            attributes: Vec::default(),
             name: name.into(),
             fields: EnumFields::default(),
+            discriminant: None,
         }
     }
 }
@@ -31,6 +43,19 @@ impl WithComments for EnumCase {
     }
 }
 
+impl WithAttributes for EnumCase {
+    fn attributes(&self) -> &[String] {
+        self.attributes.as_slice()
+    }
+
+    fn add_attribute<S>(&mut self, attribute: S)
+    where
+        S: Into<String>,
+    {
+        self.attributes.push(attribute.into());
+    }
+}
+
 impl WithName for EnumCase {
     fn name(&self) -> &str {
         self.name.as_str()
@@ -63,13 +88,74 @@ impl EnumCase {
     }
 }
 
+impl EnumCase {
+    //! Discriminant
+
+    /// Gets the optional discriminant expression code.
+    pub fn discriminant(&self) -> Option<&str> {
+        self.discriminant.as_deref()
+    }
+
+    /// Sets the `discriminant` expression code. (ex: `0x00`)
+    pub fn set_discriminant<S>(&mut self, discriminant: S)
+    where
+        S: Into<String>,
+    {
+        self.discriminant = Some(discriminant.into());
+    }
+
+    /// Sets the `discriminant` expression code. (ex: `0x00`)
+    pub fn with_discriminant<S>(mut self, discriminant: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.set_discriminant(discriminant);
+        self
+    }
+}
+
 impl Statement for EnumCase {
     fn write(&self, b: &mut CodeBuffer, level: usize) {
+        b.open_span("variant", self.name());
         self.write_comments(CommentType::OuterLineDoc, b, level);
+        self.write_attributes(b, level);
         b.indent(level);
         self.write_name(b);
         self.fields.write(b);
+        if let Some(discriminant) = &self.discriminant {
+            b.write(" = ");
+            b.write(discriminant.as_str());
+        }
         b.write(",");
         b.end_line();
+        b.close_span();
+    }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        vec![
+            Node::Comments(&self.comments),
+            Node::Expression(&self.fields),
+        ]
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        vec![
+            NodeMut::Comments(&mut self.comments),
+            NodeMut::Expression(&mut self.fields),
+        ]
+    }
+}
+
+impl Validate for EnumCase {
+    fn validate_with(&self, v: &mut Validator) {
+        v.node("variant", self.name(), |v| {
+            v.check_identifier(self.name());
+            if let EnumFields::Named(vars) = &self.fields {
+                for var in vars {
+                    v.node("field", var.name(), |v| v.check_identifier(var.name()));
+                }
+                v.check_unique("field", vars.iter().map(|var| var.name()));
+            }
+        });
     }
 }
@@ -1,8 +1,9 @@
-use crate::rust::{RustType, Var};
-use crate::{CodeBuffer, Expression};
+use crate::rust::{RustType, Var, WithRustType};
+use crate::{CodeBuffer, Doc, Expression, Node, NodeMut};
 
 /// Enum fields.
-#[derive(Default)]
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EnumFields {
     #[default]
     Empty,
@@ -10,32 +11,65 @@ pub enum EnumFields {
     Unnamed(Vec<RustType>),
 }
 
+impl EnumFields {
+    //! Types
+
+    /// Gets the field types.
+    pub fn types(&self) -> Vec<&RustType> {
+        match self {
+            Self::Empty => Vec::default(),
+            Self::Named(vars) => vars.iter().map(|var| var.rust_type()).collect(),
+            Self::Unnamed(tags) => tags.iter().collect(),
+        }
+    }
+}
+
 impl Expression for EnumFields {
     fn write(&self, b: &mut CodeBuffer) {
+        b.write_doc(&self.to_doc());
+    }
+
+    fn to_doc(&self) -> Doc {
         match self {
-            Self::Empty => {}
+            Self::Empty => Doc::concat([]),
             Self::Named(vars) => {
-                if let Some((first, rest)) = vars.split_first() {
-                    b.write("{ ");
-                    first.write(b);
-                    for var in rest {
-                        b.write(", ");
-                        var.write(b);
-                    }
-                    b.write(" }");
+                if vars.is_empty() {
+                    Doc::concat([])
+                } else {
+                    let vars: Vec<Doc> = vars.iter().map(|var| var.to_doc()).collect();
+                    Doc::spaced_comma_list("{", vars, "}")
                 }
             }
             Self::Unnamed(tags) => {
-                if let Some((first, rest)) = tags.split_first() {
-                    b.write("(");
-                    first.write(b);
-                    for var in rest {
-                        b.write(", ");
-                        var.write(b);
-                    }
-                    b.write(")");
+                if tags.is_empty() {
+                    Doc::concat([])
+                } else {
+                    let tags: Vec<Doc> = tags.iter().map(|tag| tag.to_doc()).collect();
+                    Doc::comma_list("(", tags, ")")
                 }
             }
         }
     }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        match self {
+            Self::Empty => Vec::default(),
+            Self::Named(vars) => vars.iter().map(|var| Node::Expression(var)).collect(),
+            Self::Unnamed(tags) => tags.iter().map(|tag| Node::Expression(tag)).collect(),
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        match self {
+            Self::Empty => Vec::default(),
+            Self::Named(vars) => vars
+                .iter_mut()
+                .map(|var| NodeMut::Expression(var))
+                .collect(),
+            Self::Unnamed(tags) => tags
+                .iter_mut()
+                .map(|tag| NodeMut::Expression(tag))
+                .collect(),
+        }
+    }
 }
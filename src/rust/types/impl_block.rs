@@ -1,17 +1,25 @@
 use crate::rust::{
-    CommentType, ConstInit, Function, RustType, TypeDec, Var, WithComments, WithFunctions,
-    WithGenerics, WithRustType, WithTypeDecs,
+    CommentType, ConstInit, Function, GenericParam, RustType, TypeDec, Validate, Validator,
+    WithComments, WithFunctions, WithGenerics, WithRustType, WithSignature, WithTypeDecs, WithVar,
 };
-use crate::{CodeBuffer, EmptyLine, Expression, IsEmpty, Statement};
+use crate::{CodeBuffer, EmptyLine, Expression, IsEmpty, Node, NodeMut, Statement, WithName};
 
 /// An impl block.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ImplBlock {
-    generics: Vec<Var>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    generics: Vec<GenericParam>,
     structure: RustType,
+    #[cfg_attr(feature = "serde", serde(default))]
     for_trait: Option<RustType>,
+    #[cfg_attr(feature = "serde", serde(default))]
     comments: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
     type_decs: Vec<TypeDec>,
+    #[cfg_attr(feature = "serde", serde(default))]
     constants: Vec<ConstInit>,
+    #[cfg_attr(feature = "serde", serde(default))]
     functions: Vec<Function>,
 }
 
@@ -30,13 +38,13 @@ impl<T: Into<RustType>> From<T> for ImplBlock {
 }
 
 impl WithGenerics for ImplBlock {
-    fn generics(&self) -> &[Var] {
+    fn generics(&self) -> &[GenericParam] {
         self.generics.as_slice()
     }
 
-    fn add_generic<V>(&mut self, generic: V)
+    fn add_generic<G>(&mut self, generic: G)
     where
-        V: Into<Var>,
+        G: Into<GenericParam>,
     {
         self.generics.push(generic.into());
     }
@@ -152,9 +160,10 @@ impl IsEmpty for ImplBlock {
 
 impl Statement for ImplBlock {
     fn write(&self, b: &mut CodeBuffer, level: usize) {
+        b.open_span("impl", self.structure.to_string());
         b.indent(level);
         b.write("impl");
-        self.write_generic_brackets(b);
+        b.write_doc(&self.generic_impl_brackets_doc());
         b.space();
         if let Some(for_trait) = self.for_trait() {
             for_trait.write(b);
@@ -174,5 +183,48 @@ impl Statement for ImplBlock {
             self.write_functions(b, level + 1);
             b.line(level, "}");
         }
+        b.close_span();
+    }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        let mut children: Vec<Node> = self.generics.iter().map(|c| Node::Expression(c)).collect();
+        children.push(Node::Expression(&self.structure));
+        children.extend(self.for_trait.as_ref().map(|c| Node::Expression(c)));
+        children.push(Node::Comments(&self.comments));
+        children.extend(self.type_decs.iter().map(|c| Node::Statement(c)));
+        children.extend(self.constants.iter().map(|c| Node::Statement(c)));
+        children.extend(self.functions.iter().map(|c| Node::Statement(c)));
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        let mut children: Vec<NodeMut> = self
+            .generics
+            .iter_mut()
+            .map(|c| NodeMut::Expression(c))
+            .collect();
+        children.push(NodeMut::Expression(&mut self.structure));
+        children.extend(self.for_trait.as_mut().map(|c| NodeMut::Expression(c)));
+        children.push(NodeMut::Comments(&mut self.comments));
+        children.extend(self.type_decs.iter_mut().map(|c| NodeMut::Statement(c)));
+        children.extend(self.constants.iter_mut().map(|c| NodeMut::Statement(c)));
+        children.extend(self.functions.iter_mut().map(|c| NodeMut::Statement(c)));
+        children
+    }
+}
+
+impl Validate for ImplBlock {
+    fn validate_with(&self, v: &mut Validator) {
+        v.node("impl", self.structure.to_string().as_str(), |v| {
+            let types = std::iter::once(&self.structure).chain(self.for_trait.as_ref());
+            v.check_generics(&self.generics, types);
+            v.check_no_generic_defaults(&self.generics);
+            self.type_decs.iter().for_each(|t| t.validate_with(v));
+            v.check_unique("type", self.type_decs.iter().map(|t| t.name()));
+            self.constants.iter().for_each(|c| c.validate_with(v));
+            v.check_unique("const", self.constants.iter().map(|c| c.var().name()));
+            self.functions.iter().for_each(|f| f.validate_with(v));
+            v.check_unique("fn", self.functions.iter().map(|f| f.signature().name()));
+        });
     }
 }
@@ -3,6 +3,7 @@ pub use r#enum::*;
 pub use r#struct::*;
 pub use r#trait::*;
 pub use type_dec::*;
+pub use union::*;
 pub use with_derives::*;
 pub use with_type_decs::*;
 
@@ -11,5 +12,6 @@ mod impl_block;
 mod r#struct;
 mod r#trait;
 mod type_dec;
+mod union;
 mod with_derives;
 mod with_type_decs;
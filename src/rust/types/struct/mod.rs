@@ -1,7 +1,11 @@
 pub use r#struct::*;
 pub use struct_field::*;
+pub use struct_kind::*;
+pub use tuple_field::*;
 pub use with_struct_fields::*;
 
 mod r#struct;
 mod struct_field;
+mod struct_kind;
+mod tuple_field;
 mod with_struct_fields;
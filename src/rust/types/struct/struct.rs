@@ -1,22 +1,34 @@
 use crate::rust::{
-    Access, CommentType, StructField, Var, WithAccess, WithAttributes, WithComments, WithDerives,
-    WithGenerics, WithStructFields,
+    Access, CommentType, GenericParam, Ident, StructField, StructKind, TupleField, Validate,
+    Validator, WithAccess, WithAttributes, WithComments, WithDerives, WithGenerics, WithRustType,
+    WithStructFields,
 };
-use crate::{CodeBuffer, Statement, WithName};
+use crate::{CodeBuffer, Doc, Expression, Node, NodeMut, Statement, ValidationErrorKind, WithName};
 
 /// A struct declaration.
 #[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Struct {
+    #[cfg_attr(feature = "serde", serde(default))]
     comments: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
     derives: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
     attributes: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
     access: Access,
-    name: String,
-    generics: Vec<Var>,
+    name: Ident,
+    #[cfg_attr(feature = "serde", serde(default))]
+    generics: Vec<GenericParam>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    kind: StructKind,
+    #[cfg_attr(feature = "serde", serde(default))]
     fields: Vec<StructField>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    tuple_fields: Vec<TupleField>,
 }
 
-impl<S: Into<String>> From<S> for Struct {
+impl<S: Into<Ident>> From<S> for Struct {
     fn from(name: S) -> Self {
         Self {
             comments: Vec::default(),
@@ -25,7 +37,9 @@ impl<S: Into<String>> From<S> for Struct {
             access: Access::default(),
             name: name.into(),
             generics: Vec::default(),
+            kind: StructKind::default(),
             fields: Vec::default(),
+            tuple_fields: Vec::default(),
         }
     }
 }
@@ -89,18 +103,64 @@ impl WithName for Struct {
 }
 
 impl WithGenerics for Struct {
-    fn generics(&self) -> &[Var] {
+    fn generics(&self) -> &[GenericParam] {
         self.generics.as_slice()
     }
 
-    fn add_generic<V>(&mut self, generic: V)
+    fn add_generic<G>(&mut self, generic: G)
     where
-        V: Into<Var>,
+        G: Into<GenericParam>,
     {
         self.generics.push(generic.into())
     }
 }
 
+impl Struct {
+    //! Kind
+
+    /// Gets the struct kind.
+    pub fn kind(&self) -> StructKind {
+        self.kind
+    }
+
+    /// Sets the struct `kind`. (only the fields of the kind are written, validation rejects others)
+    pub fn set_kind(&mut self, kind: StructKind) {
+        self.kind = kind;
+    }
+
+    /// Sets the struct `kind`. (only the fields of the kind are written, validation rejects others)
+    pub fn with_kind(mut self, kind: StructKind) -> Self {
+        self.set_kind(kind);
+        self
+    }
+}
+
+impl Struct {
+    //! Tuple Fields
+
+    /// Gets the tuple fields.
+    pub fn tuple_fields(&self) -> &[TupleField] {
+        self.tuple_fields.as_slice()
+    }
+
+    /// Adds the tuple `field`.
+    pub fn add_tuple_field<F>(&mut self, field: F)
+    where
+        F: Into<TupleField>,
+    {
+        self.tuple_fields.push(field.into());
+    }
+
+    /// Adds the tuple `field`.
+    pub fn with_tuple_field<F>(mut self, field: F) -> Self
+    where
+        F: Into<TupleField>,
+    {
+        self.add_tuple_field(field);
+        self
+    }
+}
+
 impl WithStructFields for Struct {
     fn fields(&self) -> &[StructField] {
         self.fields.as_slice()
@@ -116,6 +176,7 @@ impl WithStructFields for Struct {
 
 impl Statement for Struct {
     fn write(&self, b: &mut CodeBuffer, level: usize) {
+        b.open_span("struct", self.name());
         self.write_comments(CommentType::OuterLineDoc, b, level);
         self.write_derives(b, level);
         self.write_attributes(b, level);
@@ -123,15 +184,82 @@ impl Statement for Struct {
         self.write_access(b);
         b.write("struct ");
         self.write_name(b);
-        self.write_generic_brackets(b);
-        b.write(" {");
-        if self.fields.is_empty() {
-            b.write("}");
-            b.end_line();
-        } else {
-            b.end_line();
-            self.write_fields(b, level + 1);
-            b.line(level, "}");
+        match self.kind {
+            StructKind::Named => {
+                self.write_generic_brackets(b);
+                b.write(" {");
+                if self.fields.is_empty() {
+                    b.write("}");
+                    b.end_line();
+                } else {
+                    b.end_line();
+                    self.write_fields(b, level + 1);
+                    b.line(level, "}");
+                }
+            }
+            StructKind::Tuple => {
+                b.write_doc(&self.generic_params_doc());
+                let fields: Vec<Doc> = self.tuple_fields.iter().map(|f| f.to_doc()).collect();
+                b.write_doc(&Doc::comma_list("(", fields, ")"));
+                b.write_doc(&self.generic_where_doc());
+                b.write(";");
+                b.end_line();
+            }
+            StructKind::Unit => {
+                b.write_doc(&self.generic_params_doc());
+                b.write_doc(&self.generic_where_doc());
+                b.write(";");
+                b.end_line();
+            }
         }
+        b.close_span();
+    }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        let mut children: Vec<Node> = vec![Node::Comments(&self.comments)];
+        children.extend(self.generics.iter().map(|c| Node::Expression(c)));
+        children.extend(self.fields.iter().map(|c| Node::Statement(c)));
+        children.extend(self.tuple_fields.iter().map(|c| Node::Expression(c)));
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        let mut children: Vec<NodeMut> = vec![NodeMut::Comments(&mut self.comments)];
+        children.extend(self.generics.iter_mut().map(|c| NodeMut::Expression(c)));
+        children.extend(self.fields.iter_mut().map(|c| NodeMut::Statement(c)));
+        children.extend(self.tuple_fields.iter_mut().map(|c| NodeMut::Expression(c)));
+        children
+    }
+}
+
+impl Validate for Struct {
+    fn validate_with(&self, v: &mut Validator) {
+        v.node("struct", self.name(), |v| {
+            v.check_identifier(self.name());
+            let named: bool = self.kind == StructKind::Named;
+            let tuple: bool = self.kind == StructKind::Tuple;
+            if (!named && !self.fields.is_empty()) || (!tuple && !self.tuple_fields.is_empty()) {
+                v.error(ValidationErrorKind::MismatchedFields {
+                    kind: match self.kind {
+                        StructKind::Named => "named struct",
+                        StructKind::Tuple => "tuple struct",
+                        StructKind::Unit => "unit struct",
+                    },
+                });
+            }
+            match self.kind {
+                StructKind::Named => {
+                    let types = self.fields.iter().map(|f| f.rust_type());
+                    v.check_generics(&self.generics, types);
+                    self.fields.iter().for_each(|f| f.validate_with(v));
+                    v.check_unique("field", self.fields.iter().map(|f| f.name()));
+                }
+                StructKind::Tuple => {
+                    let types = self.tuple_fields.iter().map(|f| f.rust_type());
+                    v.check_generics(&self.generics, types);
+                }
+                StructKind::Unit => v.check_generics(&self.generics, []),
+            }
+        });
     }
 }
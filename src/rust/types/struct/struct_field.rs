@@ -1,10 +1,15 @@
-use crate::rust::{Access, RustType, Var, WithAccess, WithAttributes, WithRustType, WithVar};
-use crate::{CodeBuffer, Statement, WithName};
+use crate::rust::{
+    Access, RustType, Validate, Validator, Var, WithAccess, WithAttributes, WithRustType, WithVar,
+};
+use crate::{CodeBuffer, Node, NodeMut, Statement, WithName};
 
 /// A field of a struct declaration.
 #[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StructField {
+    #[cfg_attr(feature = "serde", serde(default))]
     attributes: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
     access: Access,
     var: Var,
 }
@@ -65,11 +70,27 @@ impl WithVar for StructField {
 
 impl Statement for StructField {
     fn write(&self, b: &mut CodeBuffer, level: usize) {
+        b.open_span("field", self.name());
         self.write_attributes(b, level);
         b.indent(level);
         self.write_access(b);
         self.write_var(b);
         b.write(",");
         b.end_line();
+        b.close_span();
+    }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        vec![Node::Expression(&self.var)]
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        vec![NodeMut::Expression(&mut self.var)]
+    }
+}
+
+impl Validate for StructField {
+    fn validate_with(&self, v: &mut Validator) {
+        v.node("field", self.name(), |v| v.check_identifier(self.name()));
     }
 }
@@ -0,0 +1,14 @@
+/// The kind of a struct.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StructKind {
+    /// A struct with named fields. (ex: `struct Name { a: A }`)
+    #[default]
+    Named,
+
+    /// A struct with tuple fields. (ex: `struct Name(A);`)
+    Tuple,
+
+    /// A struct without fields. (ex: `struct Name;`)
+    Unit,
+}
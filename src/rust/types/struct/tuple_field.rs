@@ -0,0 +1,81 @@
+use crate::rust::{Access, RustType, WithAccess, WithAttributes, WithRustType};
+use crate::{CodeBuffer, Doc, Expression, Node, NodeMut};
+
+/// A tuple struct field.
+///
+/// The attributes are written inline before the field. (ex: `#[serde(skip)] pub u64`)
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TupleField {
+    #[cfg_attr(feature = "serde", serde(default))]
+    attributes: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    access: Access,
+    rust_type: RustType,
+}
+
+impl<T: Into<RustType>> From<T> for TupleField {
+    fn from(rust_type: T) -> Self {
+        Self {
+            attributes: Vec::default(),
+            access: Access::default(),
+            rust_type: rust_type.into(),
+        }
+    }
+}
+
+impl WithAttributes for TupleField {
+    fn attributes(&self) -> &[String] {
+        self.attributes.as_slice()
+    }
+
+    fn add_attribute<S>(&mut self, attribute: S)
+    where
+        S: Into<String>,
+    {
+        self.attributes.push(attribute.into());
+    }
+}
+
+impl WithAccess for TupleField {
+    fn access(&self) -> &Access {
+        &self.access
+    }
+
+    fn set_access<A>(&mut self, access: A)
+    where
+        A: Into<Access>,
+    {
+        self.access = access.into();
+    }
+}
+
+impl WithRustType for TupleField {
+    fn rust_type(&self) -> &RustType {
+        &self.rust_type
+    }
+}
+
+impl Expression for TupleField {
+    fn write(&self, b: &mut CodeBuffer) {
+        b.write_doc(&self.to_doc());
+    }
+
+    fn to_doc(&self) -> Doc {
+        let mut docs: Vec<Doc> = Vec::with_capacity(self.attributes.len() + 2);
+        for attribute in &self.attributes {
+            docs.push(Doc::from(format!("#[{}] ", attribute)));
+        }
+        docs.push(self.access.to_doc());
+        docs.push(self.rust_type.to_doc());
+        Doc::concat(docs)
+    }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        vec![Node::Expression(&self.rust_type)]
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        vec![NodeMut::Expression(&mut self.rust_type)]
+    }
+}
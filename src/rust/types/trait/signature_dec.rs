@@ -1,9 +1,12 @@
 use crate::rust::CommentType::OuterLineDoc;
-use crate::rust::{Signature, WithComments, WithSignature};
-use crate::{CodeBuffer, Statement};
+use crate::rust::{Signature, Validate, Validator, WithComments, WithSignature};
+use crate::{CodeBuffer, Node, NodeMut, Statement, WithName};
 
 /// A function signature declaration.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SignatureDec {
+    #[cfg_attr(feature = "serde", serde(default))]
     comments: Vec<String>,
     signature: Signature,
 }
@@ -38,11 +41,33 @@ impl WithSignature for SignatureDec {
 
 impl Statement for SignatureDec {
     fn write(&self, b: &mut CodeBuffer, level: usize) {
+        b.open_span("fn", self.signature().name());
         self.write_comments(OuterLineDoc, b, level);
         b.indent(level);
         b.write("fn ");
         self.write_signature(b);
         b.write(";");
         b.end_line();
+        b.close_span();
+    }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        vec![
+            Node::Comments(&self.comments),
+            Node::Expression(&self.signature),
+        ]
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        vec![
+            NodeMut::Comments(&mut self.comments),
+            NodeMut::Expression(&mut self.signature),
+        ]
+    }
+}
+
+impl Validate for SignatureDec {
+    fn validate_with(&self, v: &mut Validator) {
+        self.signature.validate_with(v);
     }
 }
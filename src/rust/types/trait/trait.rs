@@ -1,17 +1,27 @@
 use crate::rust::CommentType::OuterLineDoc;
 use crate::rust::{
-    Access, Function, SignatureDec, WithAccess, WithAttributes, WithComments, WithFunctions,
-    WithTraitFunctions,
+    Access, Function, GenericParam, Signature, SignatureDec, Validate, Validator, WithAccess,
+    WithAttributes, WithComments, WithFunctions, WithGenerics, WithResult, WithRustType,
+    WithSignature, WithTraitFunctions, WithVarParams,
 };
-use crate::{CodeBuffer, IsEmpty, Statement, WithName};
+use crate::{CodeBuffer, IsEmpty, Node, NodeMut, Statement, WithName};
 
 /// A trait declaration.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Trait {
+    #[cfg_attr(feature = "serde", serde(default))]
     comments: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
     attributes: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
     access: Access,
     name: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    generics: Vec<GenericParam>,
+    #[cfg_attr(feature = "serde", serde(default))]
     trait_functions: Vec<SignatureDec>,
+    #[cfg_attr(feature = "serde", serde(default))]
     functions: Vec<Function>,
 }
 
@@ -22,6 +32,7 @@ impl<S: Into<String>> From<S> for Trait {
             attributes: Vec::default(),
             access: Access::default(),
             name: name.into(),
+            generics: Vec::default(),
             trait_functions: Vec::default(),
             functions: Vec::default(),
         }
@@ -73,6 +84,19 @@ impl WithName for Trait {
     }
 }
 
+impl WithGenerics for Trait {
+    fn generics(&self) -> &[GenericParam] {
+        self.generics.as_slice()
+    }
+
+    fn add_generic<G>(&mut self, generic: G)
+    where
+        G: Into<GenericParam>,
+    {
+        self.generics.push(generic.into())
+    }
+}
+
 impl WithTraitFunctions for Trait {
     fn signature_decs(&self) -> &[SignatureDec] {
         self.trait_functions.as_slice()
@@ -107,12 +131,15 @@ impl IsEmpty for Trait {
 
 impl Statement for Trait {
     fn write(&self, b: &mut CodeBuffer, level: usize) {
+        b.open_span("trait", self.name());
         self.write_comments(OuterLineDoc, b, level);
         self.write_attributes(b, level);
         b.indent(level);
         self.write_access(b);
         b.write("trait ");
         self.write_name(b);
+        b.write_doc(&self.generic_params_doc());
+        b.write_doc(&self.generic_where_doc());
         b.write(" {");
         if self.is_empty() {
             b.write("}");
@@ -129,5 +156,48 @@ impl Statement for Trait {
             }
             b.line(level, "}");
         }
+        b.close_span();
+    }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        let mut children: Vec<Node> = vec![Node::Comments(&self.comments)];
+        children.extend(self.generics.iter().map(|c| Node::Expression(c)));
+        children.extend(self.trait_functions.iter().map(|c| Node::Statement(c)));
+        children.extend(self.functions.iter().map(|c| Node::Statement(c)));
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        let mut children: Vec<NodeMut> = vec![NodeMut::Comments(&mut self.comments)];
+        children.extend(self.generics.iter_mut().map(|c| NodeMut::Expression(c)));
+        children.extend(
+            self.trait_functions
+                .iter_mut()
+                .map(|c| NodeMut::Statement(c)),
+        );
+        children.extend(self.functions.iter_mut().map(|c| NodeMut::Statement(c)));
+        children
+    }
+}
+
+impl Validate for Trait {
+    fn validate_with(&self, v: &mut Validator) {
+        v.node("trait", self.name(), |v| {
+            v.check_identifier(self.name());
+            let signatures: Vec<&Signature> = self
+                .trait_functions
+                .iter()
+                .map(|f| f.signature())
+                .chain(self.functions.iter().map(|f| f.signature()))
+                .collect();
+            let types = signatures.iter().copied().flat_map(|s| {
+                let params = s.params().iter().map(|p| p.rust_type());
+                params.chain(s.result())
+            });
+            v.check_generics(&self.generics, types);
+            self.trait_functions.iter().for_each(|f| f.validate_with(v));
+            self.functions.iter().for_each(|f| f.validate_with(v));
+            v.check_unique("fn", signatures.iter().map(|s| s.name()));
+        });
     }
 }
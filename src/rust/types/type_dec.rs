@@ -1,11 +1,16 @@
 use crate::rust::CommentType::OuterLineDoc;
-use crate::rust::{Access, RustType, Var, WithAccess, WithComments, WithRustType, WithVar};
-use crate::{CodeBuffer, Statement, WithName};
+use crate::rust::{
+    Access, RustType, Validate, Validator, Var, WithAccess, WithComments, WithRustType, WithVar,
+};
+use crate::{CodeBuffer, Node, NodeMut, Statement, WithName};
 
 /// A type declaration.
-#[derive(Debug)]
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TypeDec {
+    #[cfg_attr(feature = "serde", serde(default))]
     comments: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
     access: Access,
     var: Var,
 }
@@ -66,6 +71,7 @@ impl WithVar for TypeDec {
 
 impl Statement for TypeDec {
     fn write(&self, b: &mut CodeBuffer, level: usize) {
+        b.open_span("type", self.name());
         self.write_comments(OuterLineDoc, b, level);
         b.indent(level);
         b.write("type ");
@@ -74,5 +80,23 @@ impl Statement for TypeDec {
         self.write_rust_type(b);
         b.write(";");
         b.end_line();
+        b.close_span();
+    }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        vec![Node::Comments(&self.comments), Node::Expression(&self.var)]
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        vec![
+            NodeMut::Comments(&mut self.comments),
+            NodeMut::Expression(&mut self.var),
+        ]
+    }
+}
+
+impl Validate for TypeDec {
+    fn validate_with(&self, v: &mut Validator) {
+        v.node("type", self.name(), |v| v.check_identifier(self.name()));
     }
 }
@@ -0,0 +1,241 @@
+use crate::rust::{
+    Access, CommentType, Function, GenericParam, Ident, ImplBlock, Receiver, Reference, RustType,
+    Signature, StructField, Validate, Validator, WithAccess, WithAttributes, WithComments,
+    WithDerives, WithFunctions, WithGenerics, WithReceiver, WithResult, WithRustType,
+    WithStructFields, WithUnsafeFlag,
+};
+use crate::{
+    CodeBuffer, Literal, Node, NodeMut, Statement, ValidationErrorKind, WithName, WithStatements,
+};
+
+/// A union declaration.
+///
+/// Reading a union field is unsafe, the `read_function` and `read_impl` helpers generate `unsafe`
+/// accessors for the fields. A union needs at least one field, a union without fields is written
+/// as `union Name {}` which does not compile and is rejected by validation.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Union {
+    #[cfg_attr(feature = "serde", serde(default))]
+    comments: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    derives: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    attributes: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    access: Access,
+    name: Ident,
+    #[cfg_attr(feature = "serde", serde(default))]
+    generics: Vec<GenericParam>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    fields: Vec<StructField>,
+}
+
+impl<S: Into<Ident>> From<S> for Union {
+    fn from(name: S) -> Self {
+        Self {
+            comments: Vec::default(),
+            derives: Vec::default(),
+            attributes: Vec::default(),
+            access: Access::default(),
+            name: name.into(),
+            generics: Vec::default(),
+            fields: Vec::default(),
+        }
+    }
+}
+
+impl WithComments for Union {
+    fn comments(&self) -> &[String] {
+        self.comments.as_slice()
+    }
+
+    fn add_comment<S>(&mut self, comment: S)
+    where
+        S: Into<String>,
+    {
+        self.comments.push(comment.into())
+    }
+}
+
+impl WithDerives for Union {
+    fn derives(&self) -> &[String] {
+        self.derives.as_slice()
+    }
+
+    fn add_derive<S>(&mut self, derive: S)
+    where
+        S: Into<String>,
+    {
+        self.derives.push(derive.into());
+    }
+}
+
+impl WithAttributes for Union {
+    fn attributes(&self) -> &[String] {
+        self.attributes.as_slice()
+    }
+
+    fn add_attribute<S>(&mut self, attribute: S)
+    where
+        S: Into<String>,
+    {
+        self.attributes.push(attribute.into())
+    }
+}
+
+impl WithAccess for Union {
+    fn access(&self) -> &Access {
+        &self.access
+    }
+
+    fn set_access<A>(&mut self, access: A)
+    where
+        A: Into<Access>,
+    {
+        self.access = access.into()
+    }
+}
+
+impl WithName for Union {
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+}
+
+impl WithGenerics for Union {
+    fn generics(&self) -> &[GenericParam] {
+        self.generics.as_slice()
+    }
+
+    fn add_generic<G>(&mut self, generic: G)
+    where
+        G: Into<GenericParam>,
+    {
+        self.generics.push(generic.into())
+    }
+}
+
+impl WithStructFields for Union {
+    fn fields(&self) -> &[StructField] {
+        self.fields.as_slice()
+    }
+
+    fn add_field<F>(&mut self, field: F)
+    where
+        F: Into<StructField>,
+    {
+        self.fields.push(field.into());
+    }
+}
+
+impl Union {
+    //! Unsafe Reads
+
+    /// Creates the `unsafe` function reading the `field` by reference.
+    ///
+    /// The function has the name and access of the field. (ex: `pub unsafe fn a(&self) -> &u8`)
+    pub fn read_function(&self, field: &StructField) -> Function {
+        let signature: Signature = Signature::from(field.name())
+            .with_unsafe()
+            .with_receiver(Receiver::Borrowed)
+            .with_result(field.rust_type().clone().to_ref(Reference::default()));
+        Function::from(signature)
+            .with_comment(format!(" Reads the `{}` field.", field.name()))
+            .with_comment("")
+            .with_comment(" # Safety")
+            .with_comment(format!(
+                " The `{}` field must be the initialized field of the union.",
+                field.name()
+            ))
+            .with_access(field.access().clone())
+            .with_expression_statement(Literal::from(format!(
+                "unsafe {{ &self.{} }}",
+                field.name()
+            )))
+    }
+
+    /// Creates the impl block with the `read_function` of each field.
+    pub fn read_impl(&self) -> ImplBlock {
+        let mut structure: RustType = RustType::from(self.name());
+        for generic in &self.generics {
+            structure = structure.with_generic(generic.name());
+        }
+        let mut block: ImplBlock = ImplBlock::from(structure);
+        for generic in &self.generics {
+            block.add_generic(generic.clone().without_default());
+        }
+        for field in &self.fields {
+            block.add_function(self.read_function(field));
+        }
+        block
+    }
+}
+
+impl Statement for Union {
+    fn write(&self, b: &mut CodeBuffer, level: usize) {
+        b.open_span("union", self.name());
+        self.write_comments(CommentType::OuterLineDoc, b, level);
+        self.write_derives(b, level);
+        self.write_attributes(b, level);
+        b.indent(level);
+        self.write_access(b);
+        b.write("union ");
+        self.write_name(b);
+        self.write_generic_brackets(b);
+        b.write(" {");
+        if self.fields.is_empty() {
+            b.write("}");
+            b.end_line();
+        } else {
+            b.end_line();
+            self.write_fields(b, level + 1);
+            b.line(level, "}");
+        }
+        b.close_span();
+    }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        let mut children: Vec<Node> = vec![Node::Comments(&self.comments)];
+        children.extend(self.generics.iter().map(|c| Node::Expression(c)));
+        children.extend(self.fields.iter().map(|c| Node::Statement(c)));
+        children
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        let mut children: Vec<NodeMut> = vec![NodeMut::Comments(&mut self.comments)];
+        children.extend(self.generics.iter_mut().map(|c| NodeMut::Expression(c)));
+        children.extend(self.fields.iter_mut().map(|c| NodeMut::Statement(c)));
+        children
+    }
+}
+
+impl Validate for Union {
+    fn validate_with(&self, v: &mut Validator) {
+        v.node("union", self.name(), |v| {
+            v.check_identifier(self.name());
+            if self.fields.is_empty() {
+                v.error(ValidationErrorKind::MissingFields);
+            }
+            v.check_generics(&self.generics, self.fields.iter().map(|f| f.rust_type()));
+            self.fields.iter().for_each(|f| f.validate_with(v));
+            v.check_unique("field", self.fields.iter().map(|f| f.name()));
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rust::{Union, WithGenerics, WithStructFields};
+    use crate::{CodeBuffer, Statement};
+
+    #[test]
+    fn writes_inline_bounds() {
+        let u: Union = Union::from("U")
+            .with_generic(("T", "Copy"))
+            .with_field(("a", "T"));
+        let mut b: CodeBuffer = CodeBuffer::default();
+        u.write(&mut b, 0);
+        assert_eq!(String::from(b), "union U<T: Copy> {\n    a: T,\n}\n");
+    }
+}
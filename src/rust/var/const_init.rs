@@ -1,11 +1,18 @@
-use crate::rust::{Access, CommentType, Var, WithAccess, WithComments, WithVar};
-use crate::{CodeBuffer, Expression, Statement};
+use crate::rust::{
+    Access, CommentType, Validate, Validator, Var, WithAccess, WithComments, WithVar,
+};
+use crate::{CodeBuffer, Expression, Node, NodeMut, Statement, WithName};
 
 /// A const initialization statement.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstInit {
+    #[cfg_attr(feature = "serde", serde(default))]
     comments: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
     access: Access,
     var: Var,
+    #[cfg_attr(feature = "serde", serde(with = "crate::rust::data::expression_text"))]
     expression: Box<dyn Expression>,
 }
 
@@ -54,6 +61,7 @@ impl WithVar for ConstInit {
 
 impl Statement for ConstInit {
     fn write(&self, b: &mut CodeBuffer, level: usize) {
+        b.open_span("const", self.var.name());
         self.write_comments(CommentType::OuterLineDoc, b, level);
         b.indent(level);
         self.write_access(b);
@@ -63,5 +71,30 @@ impl Statement for ConstInit {
         self.expression.write(b);
         b.write(";");
         b.end_line();
+        b.close_span();
+    }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        vec![
+            Node::Comments(&self.comments),
+            Node::Expression(&self.var),
+            Node::Expression(self.expression.as_ref()),
+        ]
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        vec![
+            NodeMut::Comments(&mut self.comments),
+            NodeMut::Expression(&mut self.var),
+            NodeMut::Expression(self.expression.as_mut()),
+        ]
+    }
+}
+
+impl Validate for ConstInit {
+    fn validate_with(&self, v: &mut Validator) {
+        v.node("const", self.var.name(), |v| {
+            v.check_identifier(self.var.name())
+        });
     }
 }
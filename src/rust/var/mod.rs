@@ -2,6 +2,8 @@ pub use const_init::*;
 pub use reference::*;
 pub use rust_primitive::*;
 pub use rust_type::*;
+pub use static_init::*;
+pub use thread_local::*;
 pub use var::*;
 pub use var_init::*;
 pub use with_rust_type::*;
@@ -12,6 +14,8 @@ mod const_init;
 mod reference;
 mod rust_primitive;
 mod rust_type;
+mod static_init;
+mod thread_local;
 mod var;
 mod var_init;
 mod with_rust_type;
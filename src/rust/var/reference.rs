@@ -5,8 +5,14 @@ use crate::{CodeBuffer, Expression};
 /// # Default
 /// The default reference is `&`; a shared reference with no lifetime.
 #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Reference {
+    #[cfg_attr(feature = "serde", serde(default))]
     mutable: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, with = "crate::rust::data::reference_lifetime")
+    )]
     lifetime: Option<Option<char>>,
 }
 
@@ -3,6 +3,7 @@ use crate::{CodeBuffer, Expression, WithName};
 
 /// A Rust primitive.
 #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RustPrimitive {
     UnsignedInt8,
     UnsignedInt16,
@@ -1,10 +1,11 @@
 use crate::rust::RustType::*;
 use crate::rust::{Reference, RustPrimitive};
-use crate::{CodeBuffer, Expression, WithName};
+use crate::{CodeBuffer, Doc, Expression, Node, NodeMut, WithName};
 use std::fmt::{Display, Formatter};
 
 /// A Rust type.
 #[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RustType {
     /// A primitive type.
     Primitive(RustPrimitive),
@@ -100,40 +101,60 @@ impl RustType {
 
 impl Expression for RustType {
     fn write(&self, b: &mut CodeBuffer) {
+        b.write_doc(&self.to_doc());
+    }
+
+    fn to_doc(&self) -> Doc {
         match self {
-            Primitive(primitive) => b.write(primitive.name()),
-            Named(name) => b.write(name.as_str()),
-            Ref { reference, base } => {
-                reference.write(b);
-                base.write(b);
-            }
+            Primitive(primitive) => Doc::from(primitive.name()),
+            Named(name) => Doc::from(name.as_str()),
+            Ref { reference, base } => Doc::concat([reference.to_doc(), base.to_doc()]),
             Tuple(members) => {
-                b.write("(");
-                if let Some((first, rest)) = members.split_first() {
-                    first.write(b);
-                    for member in rest {
-                        b.write(", ");
-                        member.write(b);
-                    }
+                Doc::comma_list("(", members.iter().map(|m| m.to_doc()).collect(), ")")
+            }
+            Slice(base) => Doc::concat([Doc::from("["), base.to_doc(), Doc::from("]")]),
+            Generic { base, generics } => {
+                if generics.is_empty() {
+                    base.to_doc()
+                } else {
+                    Doc::concat([
+                        base.to_doc(),
+                        Doc::comma_list("<", generics.iter().map(|g| g.to_doc()).collect(), ">"),
+                    ])
                 }
-                b.write(")");
             }
-            Slice(base) => {
-                b.write("[");
-                base.write(b);
-                b.write("]");
+        }
+    }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        match self {
+            Primitive(_) | Named(_) => Vec::default(),
+            Ref { reference, base } => {
+                vec![Node::Expression(reference), Node::Expression(base.as_ref())]
+            }
+            Tuple(members) => members.iter().map(|m| Node::Expression(m)).collect(),
+            Slice(base) => vec![Node::Expression(base.as_ref())],
+            Generic { base, generics } => {
+                let mut children: Vec<Node> = vec![Node::Expression(base.as_ref())];
+                children.extend(generics.iter().map(|g| Node::Expression(g)));
+                children
             }
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        match self {
+            Primitive(_) | Named(_) => Vec::default(),
+            Ref { reference, base } => vec![
+                NodeMut::Expression(reference),
+                NodeMut::Expression(base.as_mut()),
+            ],
+            Tuple(members) => members.iter_mut().map(|m| NodeMut::Expression(m)).collect(),
+            Slice(base) => vec![NodeMut::Expression(base.as_mut())],
             Generic { base, generics } => {
-                base.write(b);
-                if let Some((first, rest)) = generics.split_first() {
-                    b.write("<");
-                    first.write(b);
-                    for generic in rest {
-                        b.write(", ");
-                        generic.write(b);
-                    }
-                    b.write(">");
-                }
+                let mut children: Vec<NodeMut> = vec![NodeMut::Expression(base.as_mut())];
+                children.extend(generics.iter_mut().map(|g| NodeMut::Expression(g)));
+                children
             }
         }
     }
@@ -0,0 +1,144 @@
+use crate::rust::{
+    Access, CommentType, Validate, Validator, Var, WithAccess, WithAttributes, WithComments,
+    WithVar,
+};
+use crate::{CodeBuffer, Expression, Node, NodeMut, Statement, WithName};
+
+/// A static item. (ex: `static NAME: Type = expression;`)
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StaticInit {
+    #[cfg_attr(feature = "serde", serde(default))]
+    comments: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    attributes: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    access: Access,
+    #[cfg_attr(feature = "serde", serde(default))]
+    is_mut: bool,
+    var: Var,
+    #[cfg_attr(feature = "serde", serde(with = "crate::rust::data::expression_text"))]
+    expression: Box<dyn Expression>,
+}
+
+impl<V: Into<Var>, E: 'static + Expression> From<(V, E)> for StaticInit {
+    fn from(t: (V, E)) -> Self {
+        Self {
+            comments: Vec::default(),
+            attributes: Vec::default(),
+            access: Access::default(),
+            is_mut: false,
+            var: t.0.into(),
+            expression: Box::new(t.1),
+        }
+    }
+}
+
+impl WithComments for StaticInit {
+    fn comments(&self) -> &[String] {
+        self.comments.as_slice()
+    }
+
+    fn add_comment<S>(&mut self, comment: S)
+    where
+        S: Into<String>,
+    {
+        self.comments.push(comment.into());
+    }
+}
+
+impl WithAttributes for StaticInit {
+    fn attributes(&self) -> &[String] {
+        self.attributes.as_slice()
+    }
+
+    fn add_attribute<S>(&mut self, attribute: S)
+    where
+        S: Into<String>,
+    {
+        self.attributes.push(attribute.into());
+    }
+}
+
+impl WithAccess for StaticInit {
+    fn access(&self) -> &Access {
+        &self.access
+    }
+
+    fn set_access<A>(&mut self, access: A)
+    where
+        A: Into<Access>,
+    {
+        self.access = access.into();
+    }
+}
+
+impl StaticInit {
+    //! Mut
+
+    /// Gets the `is_mut` flag.
+    pub fn is_mut(&self) -> bool {
+        self.is_mut
+    }
+
+    /// Sets the `is_mut` flag.
+    pub fn set_mut(&mut self, is_mut: bool) {
+        self.is_mut = is_mut;
+    }
+
+    /// Sets the `is_mut` flag.
+    pub fn with_mut(mut self, is_mut: bool) -> Self {
+        self.set_mut(is_mut);
+        self
+    }
+}
+
+impl WithVar for StaticInit {
+    fn var(&self) -> &Var {
+        &self.var
+    }
+}
+
+impl Statement for StaticInit {
+    fn write(&self, b: &mut CodeBuffer, level: usize) {
+        b.open_span("static", self.var.name());
+        self.write_comments(CommentType::OuterLineDoc, b, level);
+        self.write_attributes(b, level);
+        b.indent(level);
+        self.write_access(b);
+        b.write("static ");
+        if self.is_mut {
+            b.write("mut ");
+        }
+        self.write_var(b);
+        b.write(" = ");
+        self.expression.write(b);
+        b.write(";");
+        b.end_line();
+        b.close_span();
+    }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        vec![
+            Node::Comments(&self.comments),
+            Node::Expression(&self.var),
+            Node::Expression(self.expression.as_ref()),
+        ]
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        vec![
+            NodeMut::Comments(&mut self.comments),
+            NodeMut::Expression(&mut self.var),
+            NodeMut::Expression(self.expression.as_mut()),
+        ]
+    }
+}
+
+impl Validate for StaticInit {
+    fn validate_with(&self, v: &mut Validator) {
+        v.node("static", self.var.name(), |v| {
+            v.check_identifier(self.var.name())
+        });
+    }
+}
@@ -0,0 +1,90 @@
+use crate::rust::{StaticInit, Validate, Validator, WithVar};
+use crate::{CodeBuffer, EmptyLine, Node, NodeMut, Statement, ValidationErrorKind, WithName};
+
+/// A `thread_local!` declaration of static items.
+///
+/// The statics cannot be `mut`, thread-local mutation uses interior mutability.
+/// (ex: `static COUNT: Cell<u32> = Cell::new(0);`)
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThreadLocal {
+    #[cfg_attr(feature = "serde", serde(default))]
+    statics: Vec<StaticInit>,
+}
+
+impl ThreadLocal {
+    //! Statics
+
+    /// Gets the statics.
+    pub fn statics(&self) -> &[StaticInit] {
+        self.statics.as_slice()
+    }
+
+    /// Adds the `static` item.
+    pub fn add_static<S>(&mut self, r#static: S)
+    where
+        S: Into<StaticInit>,
+    {
+        self.statics.push(r#static.into());
+    }
+
+    /// Adds the `static` item.
+    pub fn with_static<S>(mut self, r#static: S) -> Self
+    where
+        S: Into<StaticInit>,
+    {
+        self.add_static(r#static);
+        self
+    }
+}
+
+impl Statement for ThreadLocal {
+    fn write(&self, b: &mut CodeBuffer, level: usize) {
+        b.open_span("thread_local", "");
+        b.indent(level);
+        b.write("thread_local! {");
+        if self.statics.is_empty() {
+            b.write("}");
+            b.end_line();
+        } else {
+            b.end_line();
+            if let Some((first, rest)) = self.statics.split_first() {
+                first.write(b, level + 1);
+                for r#static in rest {
+                    EmptyLine::default().write(b, level + 1);
+                    r#static.write(b, level + 1);
+                }
+            }
+            b.line(level, "}");
+        }
+        b.close_span();
+    }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        self.statics.iter().map(|c| Node::Statement(c)).collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        self.statics
+            .iter_mut()
+            .map(|c| NodeMut::Statement(c))
+            .collect()
+    }
+}
+
+impl Validate for ThreadLocal {
+    fn validate_with(&self, v: &mut Validator) {
+        for r#static in &self.statics {
+            r#static.validate_with(v);
+            if r#static.is_mut() {
+                v.node("static", r#static.var().name(), |v| {
+                    v.error(ValidationErrorKind::ConflictingFlags {
+                        first: "thread_local",
+                        second: "mut",
+                    })
+                });
+            }
+        }
+        v.check_unique("static", self.statics.iter().map(|s| s.var().name()));
+    }
+}
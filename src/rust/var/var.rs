@@ -1,14 +1,15 @@
-use crate::rust::{RustType, WithRustType};
-use crate::{CodeBuffer, Expression, WithName};
+use crate::rust::{Ident, RustType, WithRustType};
+use crate::{CodeBuffer, Doc, Expression, Node, NodeMut, WithName};
 
 /// A name with an associated Rust type.
 #[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Var {
-    name: String,
+    name: Ident,
     rust_type: RustType,
 }
 
-impl<S: Into<String>, T: Into<RustType>> From<(S, T)> for Var {
+impl<S: Into<Ident>, T: Into<RustType>> From<(S, T)> for Var {
     fn from(tuple: (S, T)) -> Self {
         Self {
             name: tuple.0.into(),
@@ -31,8 +32,22 @@ impl WithRustType for Var {
 
 impl Expression for Var {
     fn write(&self, b: &mut CodeBuffer) {
-        self.write_name(b);
-        b.write(": ");
-        self.write_rust_type(b);
+        b.write_doc(&self.to_doc());
+    }
+
+    fn to_doc(&self) -> Doc {
+        Doc::concat([
+            Doc::from(self.name()),
+            Doc::from(": "),
+            self.rust_type.to_doc(),
+        ])
+    }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        vec![Node::Expression(&self.rust_type)]
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        vec![NodeMut::Expression(&mut self.rust_type)]
     }
 }
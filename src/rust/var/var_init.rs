@@ -1,9 +1,12 @@
 use crate::rust::{Var, WithVar};
-use crate::{CodeBuffer, Expression, Statement};
+use crate::{CodeBuffer, Expression, Node, NodeMut, Statement};
 
 /// A variable initialization statement.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VarInit {
     var: Var,
+    #[cfg_attr(feature = "serde", serde(with = "crate::rust::data::expression_text"))]
     expression: Box<dyn Expression>,
 }
 
@@ -32,4 +35,18 @@ impl Statement for VarInit {
         b.write(";");
         b.end_line();
     }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        vec![
+            Node::Expression(&self.var),
+            Node::Expression(self.expression.as_ref()),
+        ]
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        vec![
+            NodeMut::Expression(&mut self.var),
+            NodeMut::Expression(self.expression.as_mut()),
+        ]
+    }
 }
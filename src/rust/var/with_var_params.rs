@@ -1,5 +1,5 @@
 use crate::rust::Var;
-use crate::{CodeBuffer, Expression};
+use crate::{CodeBuffer, Doc, Expression};
 
 /// An element with variable parameters.
 pub trait WithVarParams: Sized {
@@ -20,14 +20,13 @@ pub trait WithVarParams: Sized {
         self
     }
 
-    /// Writes the parameters.
+    /// Gets the parameter documents.
+    fn param_docs(&self) -> Vec<Doc> {
+        self.params().iter().map(|param| param.to_doc()).collect()
+    }
+
+    /// Writes the parameters. (broken across lines with a trailing comma if they do not fit)
     fn write_params(&self, b: &mut CodeBuffer) {
-        if let Some((first, rest)) = self.params().split_first() {
-            first.write(b);
-            for param in rest {
-                b.write(", ");
-                param.write(b);
-            }
-        }
+        b.write_doc(&Doc::group(Doc::comma_separated(self.param_docs())));
     }
 }
@@ -0,0 +1,21 @@
+use crate::Statement;
+
+/// Object-safe cloning of statements.
+///
+/// This is implemented for every `Clone` statement so that `Box<dyn Statement>` is `Clone`.
+pub trait CloneStatement {
+    /// Clones the statement into a box.
+    fn clone_statement(&self) -> Box<dyn Statement>;
+}
+
+impl<T: 'static + Statement + Clone> CloneStatement for T {
+    fn clone_statement(&self) -> Box<dyn Statement> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn Statement> {
+    fn clone(&self) -> Self {
+        (**self).clone_statement()
+    }
+}
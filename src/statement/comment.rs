@@ -0,0 +1,319 @@
+use crate::{CodeBuffer, CommentStyle, CommentSyntax, Statement};
+
+/// A comment of text wrapped to a width.
+///
+/// The text is split into paragraphs by empty lines and the lines of each paragraph are re-wrapped
+/// to the width. List items (`-`, `*`, `+`, `1.` or `1)`) start a new line and their wrapped lines
+/// are indented under the item text. Headings (`#`), table rows (`|`), indented code (4 spaces or a
+/// tab) and lines within code fences (` ``` ` or `~~~`) are kept intact. Block comment delimiters
+/// in the text are escaped. (ex: `*/` becomes `* /`)
+///
+/// The width includes the indentation and comment prefix. When no width is set the max width of
+/// the code buffer is used, and when neither is set the lines are written as is.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct Comment {
+    text: String,
+    syntax: Option<CommentSyntax>,
+    style: CommentStyle,
+    width: Option<usize>,
+}
+
+impl<S: Into<String>> From<S> for Comment {
+    fn from(text: S) -> Self {
+        Self {
+            text: text.into(),
+            syntax: None,
+            style: CommentStyle::default(),
+            width: None,
+        }
+    }
+}
+
+impl Comment {
+    //! Properties
+
+    /// Gets the text.
+    pub fn text(&self) -> &str {
+        self.text.as_str()
+    }
+
+    /// Gets the optional syntax. (defaults to the comment syntax of the language)
+    pub fn syntax(&self) -> Option<CommentSyntax> {
+        self.syntax
+    }
+
+    /// Sets the `syntax`.
+    pub fn set_syntax(&mut self, syntax: CommentSyntax) {
+        self.syntax = Some(syntax);
+    }
+
+    /// Sets the `syntax`.
+    pub fn with_syntax(mut self, syntax: CommentSyntax) -> Self {
+        self.set_syntax(syntax);
+        self
+    }
+
+    /// Gets the style.
+    pub fn style(&self) -> CommentStyle {
+        self.style
+    }
+
+    /// Sets the `style`. (falls back to the other style if the syntax does not support it)
+    pub fn set_style(&mut self, style: CommentStyle) {
+        self.style = style;
+    }
+
+    /// Sets the `style`. (falls back to the other style if the syntax does not support it)
+    pub fn with_style(mut self, style: CommentStyle) -> Self {
+        self.set_style(style);
+        self
+    }
+
+    /// Gets the optional width.
+    pub fn width(&self) -> Option<usize> {
+        self.width
+    }
+
+    /// Sets the `width`.
+    pub fn set_width(&mut self, width: usize) {
+        self.width = Some(width);
+    }
+
+    /// Sets the `width`.
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.set_width(width);
+        self
+    }
+}
+
+impl Comment {
+    //! Layout
+
+    /// Lays out the text lines wrapped to the text `width`. (in characters)
+    fn layout(&self, width: usize) -> Vec<String> {
+        let mut lines: Vec<String> = Vec::default();
+        let mut paragraph: Option<(&str, String, Vec<&str>)> = None;
+        let mut fenced: bool = false;
+        for line in self.text.lines().map(str::trim_end) {
+            let trimmed: &str = line.trim_start();
+            let fence: bool = trimmed.starts_with("```") || trimmed.starts_with("~~~");
+            let marker: Option<usize> = Self::list_marker(line);
+            let verbatim: bool = marker.is_none() && Self::is_verbatim(line);
+            let intact: bool =
+                fenced || fence || verbatim || line.is_empty() || Self::is_heading(trimmed);
+            if intact || marker.is_some() {
+                if let Some((first, hang, words)) = paragraph.take() {
+                    Self::wrap(first, hang.as_str(), &words, width, &mut lines);
+                }
+            }
+            if intact {
+                lines.push(line.to_string());
+                fenced ^= fence;
+            } else if let Some(marker) = marker {
+                let hang: String = " ".repeat(line[..marker].chars().count());
+                let words: Vec<&str> = line[marker..].split_whitespace().collect();
+                paragraph = Some((&line[..marker], hang, words));
+            } else if let Some((_, _, words)) = &mut paragraph {
+                words.extend(line.split_whitespace());
+            } else {
+                let first: &str = &line[..line.len() - trimmed.len()];
+                let words: Vec<&str> = trimmed.split_whitespace().collect();
+                paragraph = Some((first, first.to_string(), words));
+            }
+        }
+        if let Some((first, hang, words)) = paragraph {
+            Self::wrap(first, hang.as_str(), &words, width, &mut lines);
+        }
+        lines
+    }
+
+    /// Checks if the `line` is a table row or indented code. (ex: `| a | b |` or `    let a = 1;`)
+    fn is_verbatim(line: &str) -> bool {
+        line.trim_start().starts_with('|') || line.starts_with("    ") || line.starts_with('\t')
+    }
+
+    /// Checks if the `trimmed` line is a heading. (ex: `# Safety`)
+    fn is_heading(trimmed: &str) -> bool {
+        let rest: &str = trimmed.trim_start_matches('#');
+        rest.len() < trimmed.len() && (rest.is_empty() || rest.starts_with(' '))
+    }
+
+    /// Gets the length of the list item marker of the `line`. (including indentation and spacing)
+    fn list_marker(line: &str) -> Option<usize> {
+        let trimmed: &str = line.trim_start();
+        let digits: usize = trimmed.chars().take_while(char::is_ascii_digit).count();
+        let marker: usize = if trimmed.starts_with(['-', '*', '+']) {
+            1
+        } else if digits > 0 && trimmed[digits..].starts_with(['.', ')']) {
+            digits + 1
+        } else {
+            return None;
+        };
+        let rest: &str = &trimmed[marker..];
+        let content: &str = rest.trim_start();
+        if rest.len() == content.len() || content.is_empty() {
+            None
+        } else {
+            Some(line.len() - content.len())
+        }
+    }
+
+    /// Wraps the `words` to the `width` starting with the `first` prefix and the `hang` prefix.
+    ///
+    /// Words longer than the width are kept on a line of their own.
+    fn wrap(first: &str, hang: &str, words: &[&str], width: usize, lines: &mut Vec<String>) {
+        let mut line: String = first.to_string();
+        let mut line_width: usize = first.chars().count();
+        let mut line_words: usize = 0;
+        for word in words {
+            let word_width: usize = word.chars().count();
+            if line_words > 0 && line_width + 1 + word_width > width {
+                lines.push(line);
+                line = hang.to_string();
+                line_width = hang.chars().count();
+                line_words = 0;
+            }
+            if line_words > 0 {
+                line.push(' ');
+                line_width += 1;
+            }
+            line.push_str(word);
+            line_width += word_width;
+            line_words += 1;
+        }
+        lines.push(line);
+    }
+}
+
+impl Comment {
+    //! Write
+
+    /// Gets the optional block delimiters of the effective style with the `syntax`.
+    fn block_delimiters(
+        &self,
+        syntax: CommentSyntax,
+    ) -> Option<(&'static str, &'static str, &'static str)> {
+        match self.style {
+            CommentStyle::Block => syntax.block_delimiters(),
+            CommentStyle::Line if syntax.line_prefix().is_some() => None,
+            CommentStyle::Line => syntax.block_delimiters(),
+        }
+    }
+
+    /// Gets the comment `line` with the `prefix`.
+    fn prefixed(prefix: &str, line: &str) -> String {
+        if line.is_empty() {
+            prefix.to_string()
+        } else if prefix.is_empty() {
+            line.to_string()
+        } else {
+            format!("{} {}", prefix, line)
+        }
+    }
+}
+
+impl Statement for Comment {
+    fn write(&self, b: &mut CodeBuffer, level: usize) {
+        let syntax: CommentSyntax = self.syntax.unwrap_or_else(|| b.language().comment_syntax());
+        let block: Option<(&str, &str, &str)> = self.block_delimiters(syntax);
+        let prefix: &str = match block {
+            Some((_, prefix, _)) => prefix,
+            None => syntax.line_prefix().unwrap_or_default(),
+        };
+
+        b.indent(level);
+        let indent: usize = b.column();
+        let mut lines: Vec<String> = match self.width.or(b.max_width()) {
+            Some(width) => {
+                let used: usize = indent + prefix.chars().count() + 1;
+                self.layout(width.saturating_sub(used))
+            }
+            None => self
+                .text
+                .lines()
+                .map(|l| l.trim_end().to_string())
+                .collect(),
+        };
+        if lines.is_empty() {
+            lines.push(String::default());
+        }
+
+        let mut rows: Vec<String> = Vec::with_capacity(lines.len() + 2);
+        if let Some((open, _, _)) = block {
+            rows.push(open.to_string());
+        }
+        rows.extend(lines.iter().map(|line| match block {
+            Some(_) => Self::prefixed(prefix, syntax.escape_block(line.as_str()).as_ref()),
+            None => Self::prefixed(prefix, line.as_str()),
+        }));
+        if let Some((_, _, close)) = block {
+            rows.push(close.to_string());
+        }
+        for (i, row) in rows.iter().enumerate() {
+            if i > 0 {
+                b.indent(level);
+            }
+            b.write(row.as_str());
+            b.end_line();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CodeBuffer, Comment, CommentStyle, Statement};
+
+    fn render(comment: Comment) -> String {
+        let mut b: CodeBuffer = CodeBuffer::default();
+        comment.write(&mut b, 0);
+        b.into()
+    }
+
+    #[test]
+    fn wraps_paragraphs() {
+        let comment: Comment = Comment::from("one two three\nfour five six").with_width(14);
+        assert_eq!(render(comment), "// one two\n// three four\n// five six\n");
+    }
+
+    #[test]
+    fn hangs_list_items() {
+        let comment: Comment = Comment::from("- one two three four").with_width(16);
+        assert_eq!(render(comment), "// - one two\n//   three four\n");
+    }
+
+    #[test]
+    fn keeps_code_fences() {
+        let text: &str = "```\nlet a = one(two, three);\n```";
+        let comment: Comment = Comment::from(text).with_width(16);
+        assert_eq!(
+            render(comment),
+            "// ```\n// let a = one(two, three);\n// ```\n"
+        );
+    }
+
+    #[test]
+    fn keeps_tables() {
+        let text: &str = "| a | b |\n|---|---|\n| 1 | 2 |";
+        let comment: Comment = Comment::from(text).with_width(40);
+        assert_eq!(
+            render(comment),
+            "// | a | b |\n// |---|---|\n// | 1 | 2 |\n"
+        );
+    }
+
+    #[test]
+    fn keeps_indented_code() {
+        let text: &str = "Example:\n    let a = one(two, three);\n    let b = a;";
+        let comment: Comment = Comment::from(text).with_width(16);
+        assert_eq!(
+            render(comment),
+            "// Example:\n//     let a = one(two, three);\n//     let b = a;\n"
+        );
+    }
+
+    #[test]
+    fn escapes_block_delimiters() {
+        let comment: Comment = Comment::from("a */ b").with_style(CommentStyle::Block);
+        assert_eq!(render(comment), "/*\n * a * / b\n */\n");
+    }
+}
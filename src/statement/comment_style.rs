@@ -0,0 +1,10 @@
+/// The style of a comment.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+pub enum CommentStyle {
+    /// Each line is prefixed with the line comment prefix.
+    #[default]
+    Line,
+
+    /// The lines are enclosed in the block comment delimiters.
+    Block,
+}
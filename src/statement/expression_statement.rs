@@ -1,6 +1,7 @@
-use crate::{CodeBuffer, Expression, Statement};
+use crate::{CodeBuffer, Expression, Node, NodeMut, Statement};
 
 /// A statement that wraps an expression.
+#[derive(Clone, Debug)]
 pub struct ExpressionStatement<E: Expression> {
     expression: E,
 }
@@ -11,10 +12,27 @@ impl<E: Expression> From<E> for ExpressionStatement<E> {
     }
 }
 
-impl<E: Expression> Statement for ExpressionStatement<E> {
+impl<E: Expression> ExpressionStatement<E> {
+    //! Properties
+
+    /// Gets the expression.
+    pub fn expression(&self) -> &E {
+        &self.expression
+    }
+}
+
+impl<E: Expression + Clone> Statement for ExpressionStatement<E> {
     fn write(&self, b: &mut CodeBuffer, level: usize) {
         b.indent(level);
         self.expression.write(b);
         b.end_line();
     }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        vec![Node::Expression(&self.expression)]
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        vec![NodeMut::Expression(&mut self.expression)]
+    }
 }
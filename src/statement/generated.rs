@@ -0,0 +1,267 @@
+use crate::{CodeBuffer, Node, NodeMut, ProtectedRegions, StableHasher, Statement, WithStatements};
+use std::hash::Hasher;
+
+/// Generated code with a banner marking it as generated by a generator.
+///
+/// The banner embeds a hash of the written code so that hand-edits can be detected with `verify`.
+/// The code is hashed to the end of the file so the banner should be the root of the file. The
+/// hash ignores trailing whitespace, line-ending styles, leading and trailing empty lines and the
+/// content of protected regions. (a normalizing buffer may hold the leading empty lines of the body
+/// pending and write them after the banner)
+///
+/// # Example
+/// ```
+/// use code_gen::{CodeBuffer, EmptyLine, Generated, GeneratedStatus, Semi, Statement};
+/// use code_gen::WithStatements;
+///
+/// let generated: Generated = Generated::from("gen")
+///     .with_statement(EmptyLine::default())
+///     .with_statement(Semi::from("let a = 1"));
+/// for mut b in [CodeBuffer::default(), CodeBuffer::default().with_normalization()] {
+///     generated.write(&mut b, 0);
+///     let code: String = b.into();
+///     assert_eq!(Generated::verify(code.as_str()), GeneratedStatus::Untouched);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Generated {
+    generator: String,
+    comment_prefix: Option<String>,
+    statements: Vec<Box<dyn Statement>>,
+}
+
+/// The status of code checked for a generated banner.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum GeneratedStatus {
+    /// The code has a banner and matches its hash.
+    Untouched,
+
+    /// The code has a banner but does not match its hash.
+    Edited,
+
+    /// The code does not have a banner.
+    NotGenerated,
+}
+
+impl Generated {
+    //! Constants
+
+    /// The generated marker. (followed by the generator)
+    pub const GENERATED_MARKER: &'static str = "@generated";
+
+    /// The hash marker. (followed by a space and the hex hash)
+    pub const HASH_MARKER: &'static str = "@generated-hash";
+
+    /// The default comment prefix of the banner lines. (if the language has no line comments)
+    pub const DEFAULT_COMMENT_PREFIX: &'static str = "//";
+}
+
+impl<S: Into<String>> From<S> for Generated {
+    fn from(generator: S) -> Self {
+        Self {
+            generator: generator.into(),
+            comment_prefix: None,
+            statements: Vec::default(),
+        }
+    }
+}
+
+impl Generated {
+    //! Properties
+
+    /// Gets the generator.
+    pub fn generator(&self) -> &str {
+        self.generator.as_str()
+    }
+
+    /// Gets the optional comment prefix of the banner lines.
+    /// (defaults to the line comment prefix of the language)
+    pub fn comment_prefix(&self) -> Option<&str> {
+        self.comment_prefix.as_deref()
+    }
+
+    /// Sets the `comment_prefix` of the banner lines.
+    pub fn set_comment_prefix<S>(&mut self, comment_prefix: S)
+    where
+        S: Into<String>,
+    {
+        self.comment_prefix = Some(comment_prefix.into());
+    }
+
+    /// Sets the `comment_prefix` of the banner lines.
+    pub fn with_comment_prefix<S>(mut self, comment_prefix: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.set_comment_prefix(comment_prefix);
+        self
+    }
+}
+
+impl Generated {
+    //! Write
+
+    /// Gets the comment prefix for the language of the buffer `b`.
+    fn resolve_comment_prefix(&self, b: &CodeBuffer) -> String {
+        match &self.comment_prefix {
+            Some(comment_prefix) => comment_prefix.clone(),
+            None => b
+                .language()
+                .comment_syntax()
+                .line_prefix()
+                .unwrap_or(Self::DEFAULT_COMMENT_PREFIX)
+                .to_string(),
+        }
+    }
+}
+
+impl WithStatements for Generated {
+    fn statements(&self) -> &[Box<dyn Statement>] {
+        self.statements.as_slice()
+    }
+
+    fn add_boxed_statement(&mut self, statement: Box<dyn Statement>) {
+        self.statements.push(statement);
+    }
+}
+
+impl Generated {
+    //! Hash
+
+    /// Hashes the generated `body`.
+    pub fn hash_body(body: &str) -> u64 {
+        let mut lines: Vec<&str> = Vec::default();
+        let mut in_region: bool = false;
+        for line in body.lines().map(str::trim_end) {
+            match ProtectedRegions::marker(line) {
+                Some((begin, _)) => {
+                    in_region = begin;
+                    lines.push(line);
+                }
+                None if !in_region => lines.push(line),
+                None => {}
+            }
+        }
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+        let leading: usize = lines.iter().take_while(|line| line.is_empty()).count();
+        let mut hasher: StableHasher = StableHasher::default();
+        for line in &lines[leading..] {
+            hasher.write(line.as_bytes());
+            hasher.write(b"\n");
+        }
+        hasher.finish()
+    }
+
+    /// Verifies the generated `code` against the hash in its banner.
+    pub fn verify(code: &str) -> GeneratedStatus {
+        let mut offset: usize = 0;
+        for line in code.split_inclusive('\n') {
+            offset += line.len();
+            if let Some(index) = line.find(Self::HASH_MARKER) {
+                let hash: Option<u64> = line[index + Self::HASH_MARKER.len()..]
+                    .split_whitespace()
+                    .next()
+                    .and_then(|hash| u64::from_str_radix(hash, 16).ok());
+                return if hash == Some(Self::hash_body(&code[offset..])) {
+                    GeneratedStatus::Untouched
+                } else {
+                    GeneratedStatus::Edited
+                };
+            }
+        }
+        GeneratedStatus::NotGenerated
+    }
+}
+
+impl Statement for Generated {
+    fn write(&self, b: &mut CodeBuffer, level: usize) {
+        let mut body: CodeBuffer = b.fork();
+        self.write_statements(&mut body, level);
+        let hash: u64 = Self::hash_body(body.peek());
+        let comment_prefix: String = self.resolve_comment_prefix(b);
+
+        b.indent(level);
+        b.write(comment_prefix.as_str());
+        b.space();
+        b.write(Self::GENERATED_MARKER);
+        b.write(" by ");
+        b.write(self.generator.as_str());
+        b.write(", do not edit");
+        b.end_line();
+
+        b.indent(level);
+        b.write(comment_prefix.as_str());
+        b.space();
+        b.write(Self::HASH_MARKER);
+        b.space();
+        b.write(format!("{:016x}", hash).as_str());
+        b.end_line();
+
+        b.append(body);
+    }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        vec![Node::Statements(&self.statements)]
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        vec![NodeMut::Statements(&mut self.statements)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        CodeBuffer, Generated, GeneratedStatus, ProtectedRegion, Semi, Statement, WithStatements,
+    };
+
+    /// Writes generated code with a protected region.
+    fn generate() -> String {
+        let generated: Generated = Generated::from("gen")
+            .with_statement(Semi::from("let a = 1"))
+            .with_statement(ProtectedRegion::from("user").with_statement(Semi::from("let b = 2")))
+            .with_statement(Semi::from("let c = 3"));
+        let mut b: CodeBuffer = CodeBuffer::default();
+        generated.write(&mut b, 0);
+        b.into()
+    }
+
+    #[test]
+    fn verifies_untouched_code() {
+        let code: String = generate();
+        assert_eq!(Generated::verify(code.as_str()), GeneratedStatus::Untouched);
+    }
+
+    #[test]
+    fn detects_edits() {
+        let code: String = generate().replace("let a = 1", "let a = 2");
+        assert_eq!(Generated::verify(code.as_str()), GeneratedStatus::Edited);
+        let code: String = generate().replace("let c = 3;\n", "");
+        assert_eq!(Generated::verify(code.as_str()), GeneratedStatus::Edited);
+    }
+
+    #[test]
+    fn ignores_formatting_and_protected_regions() {
+        let code: String = generate()
+            .replace("let b = 2", "let b = 5")
+            .replace("let a = 1;\n", "let a = 1;  \r\n")
+            + "\n\n";
+        assert_eq!(Generated::verify(code.as_str()), GeneratedStatus::Untouched);
+    }
+
+    #[test]
+    fn detects_missing_banners() {
+        assert_eq!(
+            Generated::verify("let a = 1;\n"),
+            GeneratedStatus::NotGenerated
+        );
+    }
+
+    #[test]
+    fn detects_corrupt_hashes() {
+        let code: String = generate().replace("@generated-hash ", "@generated-hash x");
+        assert_eq!(Generated::verify(code.as_str()), GeneratedStatus::Edited);
+    }
+}
@@ -1,11 +1,23 @@
+pub use clone_statement::*;
+pub use comment::*;
+pub use comment_style::*;
 pub use empty_line::*;
 pub use expression_statement::*;
+pub use generated::*;
+pub use protected_region::*;
 pub use semi::*;
 pub use statement::*;
+pub use text_block::*;
 pub use with_statements::*;
 
+mod clone_statement;
+mod comment;
+mod comment_style;
 mod empty_line;
 mod expression_statement;
+mod generated;
+mod protected_region;
 mod semi;
 mod statement;
+mod text_block;
 mod with_statements;
@@ -0,0 +1,123 @@
+use crate::{CodeBuffer, Node, NodeMut, Statement, WithStatements};
+
+/// A user-editable region that is preserved across regeneration.
+///
+/// The region is written as begin and end marker comments with a stable id around its default
+/// statements. The content between the markers of a previously generated file is spliced back into
+/// the regenerated code with `ProtectedRegions`.
+#[derive(Clone, Debug)]
+pub struct ProtectedRegion {
+    id: String,
+    comment_prefix: Option<String>,
+    statements: Vec<Box<dyn Statement>>,
+}
+
+impl ProtectedRegion {
+    //! Constants
+
+    /// The begin marker. (followed by a space and the region id)
+    pub const BEGIN_MARKER: &'static str = "@protected-begin";
+
+    /// The end marker. (followed by a space and the region id)
+    pub const END_MARKER: &'static str = "@protected-end";
+
+    /// The default comment prefix of the marker lines. (if the language has no line comments)
+    pub const DEFAULT_COMMENT_PREFIX: &'static str = "//";
+}
+
+impl<S: Into<String>> From<S> for ProtectedRegion {
+    fn from(id: S) -> Self {
+        Self {
+            id: id.into(),
+            comment_prefix: None,
+            statements: Vec::default(),
+        }
+    }
+}
+
+impl ProtectedRegion {
+    //! Properties
+
+    /// Gets the region id.
+    pub fn id(&self) -> &str {
+        self.id.as_str()
+    }
+
+    /// Gets the optional comment prefix of the marker lines.
+    /// (defaults to the line comment prefix of the language)
+    pub fn comment_prefix(&self) -> Option<&str> {
+        self.comment_prefix.as_deref()
+    }
+
+    /// Sets the `comment_prefix` of the marker lines.
+    pub fn set_comment_prefix<S>(&mut self, comment_prefix: S)
+    where
+        S: Into<String>,
+    {
+        self.comment_prefix = Some(comment_prefix.into());
+    }
+
+    /// Sets the `comment_prefix` of the marker lines.
+    pub fn with_comment_prefix<S>(mut self, comment_prefix: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.set_comment_prefix(comment_prefix);
+        self
+    }
+}
+
+impl WithStatements for ProtectedRegion {
+    fn statements(&self) -> &[Box<dyn Statement>] {
+        self.statements.as_slice()
+    }
+
+    fn add_boxed_statement(&mut self, statement: Box<dyn Statement>) {
+        self.statements.push(statement);
+    }
+}
+
+impl ProtectedRegion {
+    //! Write
+
+    /// Gets the comment prefix for the language of the buffer `b`.
+    fn resolve_comment_prefix(&self, b: &CodeBuffer) -> String {
+        match &self.comment_prefix {
+            Some(comment_prefix) => comment_prefix.clone(),
+            None => b
+                .language()
+                .comment_syntax()
+                .line_prefix()
+                .unwrap_or(Self::DEFAULT_COMMENT_PREFIX)
+                .to_string(),
+        }
+    }
+
+    /// Writes the `marker` line.
+    fn write_marker(&self, b: &mut CodeBuffer, level: usize, marker: &str) {
+        let comment_prefix: String = self.resolve_comment_prefix(b);
+        b.indent(level);
+        b.write(comment_prefix.as_str());
+        b.space();
+        b.write(marker);
+        b.space();
+        b.write(self.id.as_str());
+        b.end_line();
+    }
+}
+
+impl Statement for ProtectedRegion {
+    fn write(&self, b: &mut CodeBuffer, level: usize) {
+        self.write_marker(b, level, Self::BEGIN_MARKER);
+        self.write_statements(b, level);
+        self.write_marker(b, level, Self::END_MARKER);
+    }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        vec![Node::Statements(&self.statements)]
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        vec![NodeMut::Statements(&mut self.statements)]
+    }
+}
@@ -1,6 +1,8 @@
-use crate::{CodeBuffer, Expression, Literal, Statement};
+use crate::{CodeBuffer, Expression, Language, Literal, Node, NodeMut, Statement};
+use std::sync::Arc;
 
-/// A semicolon ended expression statement.
+/// A terminated expression statement. (ends with the statement terminator of the language)
+#[derive(Clone, Debug)]
 pub struct Semi<E: Expression> {
     expression: E,
 }
@@ -11,6 +13,15 @@ impl<E: Expression> From<E> for Semi<E> {
     }
 }
 
+impl<E: Expression> Semi<E> {
+    //! Properties
+
+    /// Gets the expression.
+    pub fn expression(&self) -> &E {
+        &self.expression
+    }
+}
+
 impl From<&str> for Semi<Literal> {
     fn from(value: &str) -> Self {
         Self::from(Literal::from(value))
@@ -23,11 +34,20 @@ impl From<String> for Semi<Literal> {
     }
 }
 
-impl<E: Expression> Statement for Semi<E> {
+impl<E: Expression + Clone> Statement for Semi<E> {
     fn write(&self, b: &mut CodeBuffer, level: usize) {
         b.indent(level);
         self.expression.write(b);
-        b.write(";");
+        let language: Arc<dyn Language> = b.language().clone();
+        b.write(language.statement_terminator());
         b.end_line();
     }
+
+    fn children(&self) -> Vec<Node<'_>> {
+        vec![Node::Expression(&self.expression)]
+    }
+
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        vec![NodeMut::Expression(&mut self.expression)]
+    }
 }
@@ -1,7 +1,40 @@
-use crate::CodeBuffer;
+use crate::{AsAny, CloneStatement, CodeBuffer, Node, NodeMut};
+use std::fmt::Debug;
 
 /// Code that spans one or more lines.
-pub trait Statement {
+///
+/// Implementors must be `Clone + Debug + Send + Sync + 'static`. (`AsAny` and `CloneStatement` are
+/// implemented for all such types)
+pub trait Statement: AsAny + CloneStatement + Debug + Send + Sync {
     /// Writes the code to the buffer `b` at the indent `level`.
     fn write(&self, b: &mut CodeBuffer, level: usize);
+
+    /// Gets the child nodes. (in write order)
+    fn children(&self) -> Vec<Node<'_>> {
+        Vec::default()
+    }
+
+    /// Gets the mutable child nodes. (in write order)
+    fn children_mut(&mut self) -> Vec<NodeMut<'_>> {
+        Vec::default()
+    }
+}
+
+impl dyn Statement {
+    //! Downcasting
+
+    /// Checks if the statement is of type `T`.
+    pub fn is<T: Statement>(&self) -> bool {
+        self.as_any().is::<T>()
+    }
+
+    /// Downcasts the statement to type `T`.
+    pub fn downcast_ref<T: Statement>(&self) -> Option<&T> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Downcasts the statement to the mutable type `T`.
+    pub fn downcast_mut<T: Statement>(&mut self) -> Option<&mut T> {
+        self.as_any_mut().downcast_mut()
+    }
 }
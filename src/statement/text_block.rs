@@ -0,0 +1,63 @@
+use crate::{CodeBuffer, Statement};
+
+/// A block of multi-line text.
+///
+/// The common leading indentation of the text is stripped and each line is re-indented to the
+/// level the block is written at. Leading and trailing empty lines are dropped while the relative
+/// indentation and empty lines within the text are kept.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct TextBlock {
+    lines: Vec<String>,
+}
+
+impl<S: AsRef<str>> From<S> for TextBlock {
+    fn from(text: S) -> Self {
+        let lines: Vec<&str> = text.as_ref().lines().map(str::trim_end).collect();
+        let first: usize = lines.iter().position(|line| !line.is_empty()).unwrap_or(0);
+        let last: usize = lines
+            .iter()
+            .rposition(|line| !line.is_empty())
+            .map_or(0, |i| i + 1);
+        let lines: &[&str] = &lines[first..last.max(first)];
+        let indent: &str = lines
+            .iter()
+            .filter(|line| !line.is_empty())
+            .map(|line| &line[..line.len() - line.trim_start().len()])
+            .reduce(|a, b| {
+                let common: usize = a
+                    .char_indices()
+                    .zip(b.chars())
+                    .find(|((_, a), b)| a != b)
+                    .map_or(a.len().min(b.len()), |((i, _), _)| i);
+                &a[..common]
+            })
+            .unwrap_or("");
+        Self {
+            lines: lines
+                .iter()
+                .map(|line| line.get(indent.len()..).unwrap_or("").to_string())
+                .collect(),
+        }
+    }
+}
+
+impl TextBlock {
+    //! Lines
+
+    /// Gets the dedented lines.
+    pub fn lines(&self) -> &[String] {
+        self.lines.as_slice()
+    }
+}
+
+impl Statement for TextBlock {
+    fn write(&self, b: &mut CodeBuffer, level: usize) {
+        for line in &self.lines {
+            if line.is_empty() {
+                b.end_line();
+            } else {
+                b.line(level, line.as_str());
+            }
+        }
+    }
+}
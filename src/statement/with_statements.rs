@@ -1,4 +1,8 @@
-use crate::{CodeBuffer, EmptyLine, Expression, ExpressionStatement, Literal, Semi, Statement};
+use crate::{
+    CodeBuffer, EmptyLine, Expression, ExpressionStatement, Language, Literal, Semi, Statement,
+    TextBlock,
+};
+use std::sync::Arc;
 
 /// An element with statements.
 pub trait WithStatements: Sized {
@@ -46,7 +50,7 @@ pub trait WithStatements: Sized {
         self.with_expression_statement(literal.into())
     }
 
-    /// Adds the semicolon ended `literal` statement.
+    /// Adds the terminated `literal` statement.
     fn add_semi<L>(&mut self, literal: L)
     where
         L: Into<Literal>,
@@ -54,7 +58,7 @@ pub trait WithStatements: Sized {
         self.add_statement(Semi::from(literal.into()))
     }
 
-    /// Adds the semicolon ended `literal` statement.
+    /// Adds the terminated `literal` statement.
     fn with_semi<L>(self, literal: L) -> Self
     where
         L: Into<Literal>,
@@ -65,7 +69,7 @@ pub trait WithStatements: Sized {
     /// Adds the `expression` as a statement.
     fn add_expression_statement<E>(&mut self, expression: E)
     where
-        E: 'static + Expression,
+        E: 'static + Expression + Clone,
     {
         self.add_statement(ExpressionStatement::from(expression));
     }
@@ -73,11 +77,27 @@ pub trait WithStatements: Sized {
     /// Adds the `expression` as a statement.
     fn with_expression_statement<E>(self, expression: E) -> Self
     where
-        E: 'static + Expression,
+        E: 'static + Expression + Clone,
     {
         self.with_statement(ExpressionStatement::from(expression))
     }
 
+    /// Adds the multi-line `text` block.
+    fn add_text_block<S>(&mut self, text: S)
+    where
+        S: AsRef<str>,
+    {
+        self.add_statement(TextBlock::from(text));
+    }
+
+    /// Adds the multi-line `text` block.
+    fn with_text_block<S>(self, text: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        self.with_statement(TextBlock::from(text))
+    }
+
     /// Adds an empty line.
     fn add_empty_line(&mut self) {
         self.add_statement(EmptyLine::default());
@@ -96,6 +116,9 @@ pub trait WithStatements: Sized {
     }
 
     /// Writes the curly-bracketed statement block. (`level` is the outer level)
+    ///
+    /// The braces do not depend on the language of the code buffer. (for C-family items, ex: the
+    /// `rust` module)
     fn write_curly_statement_block(&self, b: &mut CodeBuffer, level: usize) {
         b.write("{");
         if self.statements().is_empty() {
@@ -107,4 +130,69 @@ pub trait WithStatements: Sized {
             b.write("}");
         }
     }
+
+    /// Writes the statement block with the delimiters of the language and ends the line.
+    /// (`level` is the outer level)
+    ///
+    /// The open delimiter is preceded by a space when the language has a close delimiter.
+    /// (ex: `if x {` and `if x:`) Indentation-based languages write the empty block statement for
+    /// an empty block.
+    fn write_statement_block(&self, b: &mut CodeBuffer, level: usize) {
+        let language: Arc<dyn Language> = b.language().clone();
+        match language.block_close() {
+            Some(close) => {
+                b.space();
+                b.write(language.block_open());
+                if !self.statements().is_empty() {
+                    b.end_line();
+                    self.write_statements(b, level + 1);
+                    b.indent(level);
+                }
+                b.write(close);
+                b.end_line();
+            }
+            None => {
+                b.write(language.block_open());
+                b.end_line();
+                if self.statements().is_empty() {
+                    b.line(level + 1, language.empty_block());
+                } else {
+                    self.write_statements(b, level + 1);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CodeBuffer, PythonLanguage, Source, WithStatements};
+
+    fn render(source: &Source, b: CodeBuffer) -> String {
+        let mut b: CodeBuffer = b;
+        b.write("if x");
+        source.write_statement_block(&mut b, 0);
+        b.into()
+    }
+
+    #[test]
+    fn writes_brace_blocks() {
+        let source: Source = Source::default().with_semi("a()");
+        assert_eq!(
+            render(&source, CodeBuffer::default()),
+            "if x {\n    a();\n}\n"
+        );
+        assert_eq!(
+            render(&Source::default(), CodeBuffer::default()),
+            "if x {}\n"
+        );
+    }
+
+    #[test]
+    fn writes_indented_blocks() {
+        let b = || CodeBuffer::default().with_language(PythonLanguage::default());
+        let source: Source = Source::default().with_semi("a()");
+        assert_eq!(render(&source, b()), "if x:\n    a()\n");
+        assert_eq!(render(&Source::default(), b()), "if x:\n    pass\n");
+    }
 }
@@ -0,0 +1,26 @@
+use crate::testing::GoldenFile;
+use crate::{CodeBuffer, LineDiff, Statement};
+
+/// Renders the `statement` with the default code buffer.
+pub fn render(statement: &dyn Statement) -> String {
+    let mut b: CodeBuffer = CodeBuffer::default();
+    statement.write(&mut b, 0);
+    b.into()
+}
+
+/// Asserts the rendered `statement` matches the `expected` code.
+///
+/// # Panics
+/// Panics with a line diff on mismatch.
+#[track_caller]
+pub fn assert_rendered(statement: &dyn Statement, expected: &str) {
+    let actual: String = render(statement);
+    if actual != expected {
+        let diff: String = LineDiff::new(expected, actual.as_str()).unified(
+            "expected",
+            "actual",
+            GoldenFile::DIFF_CONTEXT,
+        );
+        panic!("rendered code does not match the expected code\n{}", diff);
+    }
+}
@@ -0,0 +1,116 @@
+use crate::testing::render;
+use crate::{LineDiff, Statement};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A golden file of expected rendered code.
+///
+/// # Blessing
+/// When the `CODE_GEN_BLESS` environment variable is set (and not `0`) the golden file is
+/// rewritten with the actual code instead of being compared.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct GoldenFile {
+    path: PathBuf,
+}
+
+impl GoldenFile {
+    //! Constants
+
+    /// The environment variable that enables blessing.
+    pub const BLESS_VAR: &'static str = "CODE_GEN_BLESS";
+
+    /// The number of context lines in the diffs of mismatches.
+    pub const DIFF_CONTEXT: usize = 3;
+}
+
+impl<P: Into<PathBuf>> From<P> for GoldenFile {
+    fn from(path: P) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl GoldenFile {
+    //! Properties
+
+    /// Gets the path.
+    pub fn path(&self) -> &Path {
+        self.path.as_path()
+    }
+
+    /// Checks if blessing is enabled by the environment.
+    pub fn is_blessing() -> bool {
+        std::env::var_os(Self::BLESS_VAR).is_some_and(|value| !value.is_empty() && value != "0")
+    }
+}
+
+impl GoldenFile {
+    //! Check
+
+    /// Checks the `actual` code against the golden file, or blesses the golden file.
+    ///
+    /// Returns an error message with a line diff on mismatch.
+    pub fn check(&self, actual: &str) -> Result<(), String> {
+        if Self::is_blessing() {
+            return self
+                .bless(actual)
+                .map_err(|error| format!("failed to bless {}: {}", self.path.display(), error));
+        }
+        let expected: String = match fs::read_to_string(&self.path) {
+            Ok(expected) => expected,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                return Err(format!(
+                    "golden file {} does not exist (set {}=1 to create it)",
+                    self.path.display(),
+                    Self::BLESS_VAR
+                ));
+            }
+            Err(error) => {
+                return Err(format!("failed to read {}: {}", self.path.display(), error));
+            }
+        };
+        if expected == actual {
+            return Ok(());
+        }
+        let name: String = self.path.display().to_string();
+        let diff: String = LineDiff::new(expected.as_str(), actual).unified(
+            name.as_str(),
+            "actual",
+            Self::DIFF_CONTEXT,
+        );
+        Err(format!(
+            "rendered code does not match {} (set {}=1 to update it)\n{}",
+            name,
+            Self::BLESS_VAR,
+            diff
+        ))
+    }
+
+    /// Writes the `actual` code to the golden file.
+    fn bless(&self, actual: &str) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, actual)
+    }
+
+    /// Asserts the `actual` code matches the golden file, or blesses the golden file.
+    ///
+    /// # Panics
+    /// Panics with a line diff on mismatch.
+    #[track_caller]
+    pub fn assert_matches(&self, actual: &str) {
+        if let Err(message) = self.check(actual) {
+            panic!("{}", message);
+        }
+    }
+
+    /// Asserts the rendered `statement` matches the golden file, or blesses the golden file.
+    ///
+    /// # Panics
+    /// Panics with a line diff on mismatch.
+    #[track_caller]
+    pub fn assert_renders(&self, statement: &dyn Statement) {
+        self.assert_matches(render(statement).as_str());
+    }
+}
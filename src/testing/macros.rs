@@ -0,0 +1,32 @@
+/// Asserts the rendered statement matches the expected code.
+///
+/// ```
+/// use code_gen::{assert_renders, Semi};
+///
+/// assert_renders!(Semi::from("let a = 1"), "let a = 1;\n");
+/// ```
+#[macro_export]
+macro_rules! assert_renders {
+    ($statement:expr, $expected:expr $(,)?) => {
+        $crate::testing::assert_rendered(&$statement, $expected)
+    };
+}
+
+/// Asserts the rendered statement matches the golden file, or blesses the golden file.
+///
+/// The path is relative to the manifest directory of the calling crate.
+///
+/// ```
+/// use code_gen::{assert_renders_golden, Semi};
+///
+/// assert_renders_golden!(Semi::from("let a = 1"), "tests/golden/let_a.rs");
+/// ```
+#[macro_export]
+macro_rules! assert_renders_golden {
+    ($statement:expr, $path:expr $(,)?) => {
+        $crate::testing::GoldenFile::from(
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join($path),
+        )
+        .assert_renders(&$statement)
+    };
+}
@@ -0,0 +1,6 @@
+pub use assertions::*;
+pub use golden_file::*;
+
+mod assertions;
+mod golden_file;
+mod macros;